@@ -59,6 +59,16 @@ fn spawn(mut commands: Commands) {
                 Tap::new(0.5),
                 bindings![TestTap::KEY],
             ));
+            context.spawn((
+                Action::<TestMultiTap>::new(),
+                MultiTap::double(0.3),
+                bindings![TestMultiTap::KEY],
+            ));
+            context.spawn((
+                Action::<TestCharge>::new(),
+                Charge::new(1.0).with_min_charge(0.2),
+                bindings![TestCharge::KEY],
+            ));
             context.spawn((
                 Action::<TestToggle>::new(),
                 Toggle::default(),
@@ -160,6 +170,22 @@ impl TestTap {
     const KEY: KeyCode = KeyCode::Digit7;
 }
 
+#[derive(InputAction)]
+#[action_output(bool)]
+struct TestMultiTap;
+
+impl TestMultiTap {
+    const KEY: KeyCode = KeyCode::Enter;
+}
+
+#[derive(InputAction)]
+#[action_output(f32)]
+struct TestCharge;
+
+impl TestCharge {
+    const KEY: KeyCode = KeyCode::Tab;
+}
+
 #[derive(InputAction)]
 #[action_output(bool)]
 struct TestToggle;
@@ -4,8 +4,11 @@
 //! sharing a common [`EquipItem`] action but recording which slot they correspond to
 //! via the [`EquipHotbarIndex`] component.
 //! Each of these actions is bound to a different key from the number row on the keyboard.
+//!
+//! Slots can also be picked with a radial wheel: hold Tab or the left bumper,
+//! point with the mouse or the right stick, and release to equip.
 
-use bevy::prelude::*;
+use bevy::{ecs::spawn::SpawnWith, prelude::*};
 use bevy_enhanced_input::prelude::*;
 
 fn main() {
@@ -13,6 +16,7 @@ fn main() {
         .add_plugins((DefaultPlugins, EnhancedInputPlugin))
         .add_input_context::<Player>()
         .add_observer(equip)
+        .add_observer(equip_from_wheel)
         .add_systems(Startup, spawn)
         .run();
 }
@@ -34,18 +38,52 @@ fn spawn(mut commands: Commands) {
             equipped: 0,
         },
         Player,
-        actions!(Player[
-            (Action::<EquipItem>::new(), EquipHotbarIndex(0), bindings![KeyCode::Digit1]),
-            (Action::<EquipItem>::new(), EquipHotbarIndex(1), bindings![KeyCode::Digit2]),
-            (Action::<EquipItem>::new(), EquipHotbarIndex(2), bindings![KeyCode::Digit3]),
-            (Action::<EquipItem>::new(), EquipHotbarIndex(3), bindings![KeyCode::Digit4]),
-            (Action::<EquipItem>::new(), EquipHotbarIndex(4), bindings![KeyCode::Digit5]),
-            (Action::<EquipItem>::new(), EquipHotbarIndex(5), bindings![KeyCode::Digit6]),
-            (Action::<EquipItem>::new(), EquipHotbarIndex(6), bindings![KeyCode::Digit7]),
-            (Action::<EquipItem>::new(), EquipHotbarIndex(7), bindings![KeyCode::Digit8]),
-            (Action::<EquipItem>::new(), EquipHotbarIndex(8), bindings![KeyCode::Digit9]),
-            ]
-        ),
+        Actions::<Player>::spawn(SpawnWith(|context: &mut ActionSpawner<_>| {
+            let keys = [
+                KeyCode::Digit1,
+                KeyCode::Digit2,
+                KeyCode::Digit3,
+                KeyCode::Digit4,
+                KeyCode::Digit5,
+                KeyCode::Digit6,
+                KeyCode::Digit7,
+                KeyCode::Digit8,
+                KeyCode::Digit9,
+            ];
+            for (index, key) in keys.into_iter().enumerate() {
+                context.spawn((
+                    Action::<EquipItem>::new(),
+                    EquipHotbarIndex(index),
+                    bindings![key],
+                ));
+            }
+
+            let open = context
+                .spawn((
+                    Action::<OpenWheel>::new(),
+                    bindings![KeyCode::Tab, GamepadButton::LeftTrigger],
+                ))
+                .id();
+
+            // Mouse motion is relative, so it's accumulated into a cursor position.
+            context.spawn((
+                Action::<PointWheel>::new(),
+                RadialMenu::new(keys.len() as u32)
+                    .with_input(RadialInput::Accumulated { radius: 100.0 })
+                    .with_actuation(20.0),
+                Chord::single(open),
+                bindings![(Binding::mouse_motion(), Negate::y())],
+            ));
+
+            // The stick already reports a direction, so it's used directly.
+            context.spawn((
+                Action::<PointWheel>::new(),
+                RadialMenu::new(keys.len() as u32),
+                Chord::single(open),
+                DeadZone::default(),
+                Bindings::spawn(Axial::right_stick()),
+            ));
+        })),
     ));
 }
 
@@ -66,6 +104,25 @@ fn equip(
     }
 }
 
+fn equip_from_wheel(
+    select: On<RadialSelect>,
+    wheels: Query<(), With<Action<PointWheel>>>,
+    mut hotbars: Query<&mut Hotbar, With<Player>>,
+) {
+    if !wheels.contains(select.action) {
+        return;
+    }
+
+    let mut hotbar = hotbars.get_mut(select.context).unwrap();
+    hotbar.equipped = select.index as usize;
+
+    if let Some(item) = &hotbar.inventory[hotbar.equipped] {
+        println!("equipped item from wheel: {item:?}");
+    } else {
+        println!("equipped nothing from wheel");
+    }
+}
+
 #[derive(Component)]
 struct Player;
 
@@ -73,6 +130,15 @@ struct Player;
 #[action_output(bool)]
 struct EquipItem;
 
+#[derive(InputAction)]
+#[action_output(bool)]
+struct OpenWheel;
+
+/// Points at a hotbar slot while [`OpenWheel`] is held.
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct PointWheel;
+
 /// The index of the hotbar slot to equip when the [`EquipItem`] action is triggered for this entity.
 #[derive(Component)]
 struct EquipHotbarIndex(usize);
@@ -3,8 +3,8 @@
 //! Each action is represented by a new type that implements the [`InputAction`] trait.
 //! The trait defines the output type of the action, via the [`InputAction::Output`] associated type.
 //! Actions can output different types of values, such as `bool` for button-like actions
-//! (e.g., "Jump"), `f32` for single-axis actions (e.g., "Zoom"), or `Vec2`/`Vec3` for multi-axis actions
-//! (like "Movement").
+//! (e.g., "Jump"), `f32` for single-axis actions (e.g., "Zoom"), `Vec2`/`Vec3` for multi-axis actions
+//! (like "Movement"), or `i32`/`IVec2` for stepped actions (like "Hotbar Scroll").
 //!
 //! Actions belong to [contexts](crate::context) that group related actions together,
 //! allowing you to enable and disable actions based on the current game state.
@@ -47,7 +47,9 @@
 
 pub mod events;
 pub mod fns;
+pub mod history;
 pub mod mock;
+pub mod radial_menu;
 pub mod relationship;
 pub mod value;
 
@@ -58,6 +60,7 @@ use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
+use events::ReportedValue;
 use fns::ActionFns;
 
 /// Component that represents a user action.
@@ -79,6 +82,7 @@ use fns::ActionFns;
     ActionEvents,
     ActionTime,
     ActionMock,
+    ReportedValue::zero(A::Output::DIM),
 )]
 pub struct Action<A: InputAction>(A::Output);
 
@@ -128,16 +132,53 @@ pub trait InputAction: 'static {
     /// - Use [`bool`] for button-like actions (e.g., `Jump`).
     /// - Use [`f32`] for single-axis actions (e.g., `Zoom`).
     /// - For multi-axis actions, like `Movement`, use [`Vec2`] or [`Vec3`].
+    /// - For stepped actions, like `HotbarScroll` or `GridMove`, use [`i32`], [`IVec2`] or [`IVec3`].
+    /// - For per-axis button-like actions, use [`BVec2`] or [`BVec3`].
+    ///
+    /// You can also use your own type, see [`ActionOutput`] for details.
     type Output: ActionOutput;
 }
 
 /// Type which can be used as [`InputAction::Output`].
+///
+/// The action value is always evaluated as [`ActionValue`] and converted into the output
+/// type via [`From<ActionValue>`] after modifiers are applied. Integer outputs round each
+/// axis to the nearest integer, with halfway cases rounded away from zero. Boolean
+/// vector outputs are `true` for each non-zero axis.
+///
+/// # Custom outputs
+///
+/// Implement the trait for your type to represent the value in a more convenient form.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// #[derive(InputAction)]
+/// #[action_output(Slot)]
+/// struct SelectSlot;
+///
+/// /// Hotbar slot selected by scrolling, clamped to the available slots.
+/// #[derive(Default, Debug, Clone, Copy, PartialEq)]
+/// struct Slot(u8);
+///
+/// impl ActionOutput for Slot {
+///     const DIM: ActionValueDim = ActionValueDim::Axis1D;
+/// }
+///
+/// impl From<ActionValue> for Slot {
+///     fn from(value: ActionValue) -> Self {
+///         let slot: i32 = value.into();
+///         Self(slot.clamp(0, 9) as u8)
+///     }
+/// }
+/// ```
 pub trait ActionOutput:
     From<ActionValue> + Default + Send + Sync + Debug + Clone + Copy + PartialEq
 {
     /// Dimension of this output.
     ///
-    /// Used for [`ActionValue`] initialization.
+    /// Used for [`ActionValue`] initialization and conversion of the evaluated value.
     const DIM: ActionValueDim;
 }
 
@@ -157,6 +198,26 @@ impl ActionOutput for Vec3 {
     const DIM: ActionValueDim = ActionValueDim::Axis3D;
 }
 
+impl ActionOutput for i32 {
+    const DIM: ActionValueDim = ActionValueDim::Axis1D;
+}
+
+impl ActionOutput for IVec2 {
+    const DIM: ActionValueDim = ActionValueDim::Axis2D;
+}
+
+impl ActionOutput for IVec3 {
+    const DIM: ActionValueDim = ActionValueDim::Axis3D;
+}
+
+impl ActionOutput for BVec2 {
+    const DIM: ActionValueDim = ActionValueDim::Axis2D;
+}
+
+impl ActionOutput for BVec3 {
+    const DIM: ActionValueDim = ActionValueDim::Axis3D;
+}
+
 /// Behavior configuration for [`Action<C>`].
 #[derive(Component, Default, Debug, Clone, Copy)]
 #[cfg_attr(
@@ -191,7 +252,8 @@ pub struct ActionSettings {
     /// Actions are ordered by the maximum number of [`ModKeys`] in their bindings.
     /// For example, an action with a `Ctrl + C` binding is evaluated before one with just
     /// a `C` binding. If actions have the same modifier count, they are ordered by their
-    /// spawn order.
+    /// spawn order. Actions referenced by conditions, like [`Chord`], are always evaluated
+    /// before the actions that reference them.
     ///
     /// Consuming is global and affect actions in all contexts. Importantly, this does
     /// **not** affect the underlying Bevy input - only the action evaluation logic is impacted.
@@ -201,6 +263,17 @@ pub struct ActionSettings {
     ///
     /// By default set to `false`.
     pub consume_input: bool,
+
+    /// Enables [`ActionEvents::VALUE_CHANGED`] when the value differs from the
+    /// value at the last [`ActionEvents::VALUE_CHANGED`] by more than the specified epsilon.
+    ///
+    /// Changes accumulate over evaluations, so a value that moves slowly
+    /// still triggers the event once it moves far enough.
+    ///
+    /// Use `0.0` to react on any change.
+    ///
+    /// By default set to `None`, which disables the event.
+    pub value_change_epsilon: Option<f32>,
 }
 
 /// Defines how [`ActionValue`] is calculated when multiple inputs are evaluated with the
@@ -27,6 +27,9 @@ use crate::prelude::*;
 /// | [`TriggerState::Fired`]      | [`TriggerState::Ongoing`] | [`Ongoing`]               |
 /// | [`TriggerState::Fired`]      | [`TriggerState::None`]    | [`Complete`]             |
 ///
+/// Additionally, [`ValueChanged`] is triggered on value changes if enabled via
+/// [`ActionSettings::value_change_epsilon`], regardless of the state transition.
+///
 /// The meaning of each kind depends on the assigned [`InputCondition`]s. The events are
 /// triggered in the action evaluation order.
 #[derive(Component, Default, Debug, PartialEq, Eq, Clone, Copy)]
@@ -65,6 +68,8 @@ bitflags! {
         const CANCEL = 0b00001000;
         /// Corresponds to [`Complete`].
         const COMPLETE = 0b00010000;
+        /// Corresponds to [`ValueChanged`].
+        const VALUE_CHANGED = 0b00100000;
     }
 }
 
@@ -85,6 +90,94 @@ impl ActionEvents {
             (TriggerState::Fired, TriggerState::Fired) => ActionEvents::FIRE,
         }
     }
+
+    /// Like [`Self::new`], but also includes [`Self::VALUE_CHANGED`] if the value moved
+    /// by more than `epsilon` from `reported`.
+    ///
+    /// `reported` should be the value at the last [`Self::VALUE_CHANGED`] and is updated
+    /// when the event is included. Comparing against it instead of the previous value
+    /// allows detecting slow changes that accumulate over multiple evaluations.
+    ///
+    /// If `epsilon` is `None`, the value change is ignored.
+    pub fn with_value_change(
+        previous_state: TriggerState,
+        current: (TriggerState, ActionValue),
+        reported: &mut ActionValue,
+        epsilon: Option<f32>,
+    ) -> ActionEvents {
+        let mut events = Self::new(previous_state, current.0);
+        if let Some(epsilon) = epsilon {
+            let distance = reported.as_axis3d().distance_squared(current.1.as_axis3d());
+            if distance > epsilon * epsilon {
+                events |= ActionEvents::VALUE_CHANGED;
+                *reported = current.1;
+            }
+        }
+        events
+    }
+}
+
+/// Action value at the last [`ActionEvents::VALUE_CHANGED`].
+#[derive(Component, Deref, DerefMut)]
+pub(crate) struct ReportedValue(ActionValue);
+
+impl ReportedValue {
+    pub(crate) const fn zero(dim: ActionValueDim) -> Self {
+        Self(ActionValue::zero(dim))
+    }
+}
+
+/// Buffered counterpart of the action events.
+///
+/// Written during [`EnhancedInputSystems::Apply`] for each action with non-empty [`ActionEvents`],
+/// in the same order as the corresponding events are triggered.
+///
+/// Unlike observers, messages can be read with [`MessageReader`] in any system and in any schedule.
+/// This allows batch-processing events in a particular system order. For contexts registered to a fixed
+/// schedule, messages won't be lost even if the schedule doesn't run in a frame.
+///
+/// Opt-in, written only if the message is registered with [`App::add_message`].
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_message::<ActionEventMessage>()
+///     .add_systems(Update, jump);
+///
+/// fn jump(mut messages: MessageReader<ActionEventMessage>, jumps: Query<(), With<Action<Jump>>>) {
+///     for message in messages.read() {
+///         if message.events.contains(ActionEvents::START) && jumps.contains(message.action) {
+///             // ...
+///         }
+///     }
+/// }
+/// # #[derive(InputAction)]
+/// # #[action_output(bool)]
+/// # struct Jump;
+/// ```
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct ActionEventMessage {
+    /// Entity with the context component for which the events were triggered.
+    pub context: Entity,
+
+    /// Action that triggered the events.
+    pub action: Entity,
+
+    /// Triggered events.
+    pub events: ActionEvents,
+
+    /// Current action value.
+    pub value: ActionValue,
+
+    /// Current action state.
+    pub state: TriggerState,
+
+    /// Current action timing information.
+    pub time: ActionTime,
 }
 
 /// Triggers when an action switches its state from [`TriggerState::None`]
@@ -492,3 +585,81 @@ impl<A: InputAction> Clone for Complete<A> {
 }
 
 impl<A: InputAction> Copy for Complete<A> {}
+
+/// Triggers when an action value changes.
+///
+/// Disabled by default, enable it via [`ActionSettings::value_change_epsilon`].
+///
+/// Unlike other events, it doesn't depend on state transitions. Useful for analog actions
+/// that remain in the same state while their value changes, such as UI sliders or
+/// audio controls.
+///
+/// See [`ActionEvents`] for all transitions.
+///
+/// # Examples
+///
+/// Update the volume only when the value actually changes.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_enhanced_input::prelude::*;
+/// # let mut app = App::new();
+/// app.add_observer(adjust_volume);
+///
+/// app.world_mut().spawn((
+///     Player,
+///     actions!(Player[
+///         (
+///             Action::<Volume>::new(),
+///             ActionSettings {
+///                 value_change_epsilon: Some(0.01),
+///                 ..Default::default()
+///             },
+///             bindings![GamepadAxis::RightStickY],
+///         ),
+///     ])
+/// ));
+///
+/// fn adjust_volume(volume: On<ValueChanged<Volume>>, mut global_volume: ResMut<GlobalVolume>) {
+///     // ...
+/// }
+/// # #[derive(Component)]
+/// # struct Player;
+/// # #[derive(Resource)]
+/// # struct GlobalVolume;
+/// # #[derive(InputAction)]
+/// # #[action_output(f32)]
+/// # struct Volume;
+/// ```
+#[derive(EntityEvent)]
+pub struct ValueChanged<A: InputAction> {
+    /// Entity with the context component on which this event was triggered.
+    #[event_target]
+    pub context: Entity,
+
+    /// Action that triggered this event.
+    pub action: Entity,
+
+    /// Current action value.
+    pub value: A::Output,
+
+    /// Current action state.
+    pub state: TriggerState,
+}
+
+impl<A: InputAction> Debug for ValueChanged<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValueChanged")
+            .field("value", &self.value)
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl<A: InputAction> Clone for ValueChanged<A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A: InputAction> Copy for ValueChanged<A> {}
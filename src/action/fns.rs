@@ -128,6 +128,15 @@ fn trigger<A: InputAction>(
                 };
                 commands.trigger(event);
             }
+            ActionEvents::VALUE_CHANGED => {
+                let event = ValueChanged::<A> {
+                    context,
+                    action,
+                    value: value.into(),
+                    state,
+                };
+                commands.trigger(event);
+            }
             _ => unreachable!("iteration should yield only named flags"),
         }
     }
@@ -194,6 +203,69 @@ mod tests {
         assert_eq!(events, ActionEvents::FIRE);
     }
 
+    #[test]
+    fn value_changed() {
+        let mut reported = 0.5.into();
+        let events = ActionEvents::with_value_change(
+            TriggerState::Fired,
+            (TriggerState::Fired, 0.7.into()),
+            &mut reported,
+            Some(0.1),
+        );
+        assert_eq!(events, ActionEvents::FIRE | ActionEvents::VALUE_CHANGED);
+        assert_eq!(reported, 0.7.into());
+
+        let mut world = World::new();
+        world.init_resource::<TriggeredEvents>();
+        world.add_observer(
+            |_: On<ValueChanged<Test>>, mut events: ResMut<TriggeredEvents>| {
+                events.insert(ActionEvents::VALUE_CHANGED);
+            },
+        );
+
+        let fns = ActionFns::new::<Test>();
+        fns.trigger(
+            &mut world.commands(),
+            Entity::PLACEHOLDER,
+            Entity::PLACEHOLDER,
+            TriggerState::Fired,
+            events,
+            true.into(),
+            Default::default(),
+        );
+
+        world.flush();
+
+        assert_eq!(
+            *world.remove_resource::<TriggeredEvents>().unwrap(),
+            ActionEvents::VALUE_CHANGED
+        );
+    }
+
+    #[test]
+    fn value_within_epsilon() {
+        let mut reported = 0.5.into();
+        let events = ActionEvents::with_value_change(
+            TriggerState::Fired,
+            (TriggerState::Fired, 0.55.into()),
+            &mut reported,
+            Some(0.1),
+        );
+        assert_eq!(events, ActionEvents::FIRE);
+        assert_eq!(reported, 0.5.into(), "should keep the last reported value");
+    }
+
+    #[test]
+    fn value_change_disabled() {
+        let events = ActionEvents::with_value_change(
+            TriggerState::None,
+            (TriggerState::Fired, true.into()),
+            &mut false.into(),
+            None,
+        );
+        assert_eq!(events, ActionEvents::START | ActionEvents::FIRE);
+    }
+
     fn transition(initial_state: TriggerState, target_state: TriggerState) -> ActionEvents {
         let mut world = World::new();
 
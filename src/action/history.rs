@@ -0,0 +1,265 @@
+use alloc::collections::VecDeque;
+use core::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Records the last values and states of an [`Action<C>`].
+///
+/// Opt-in, insert it on the action entity to start recording. Updated during
+/// [`EnhancedInputSystems::Apply`] once per context evaluation, including evaluations
+/// from [`ActionMock`].
+///
+/// Useful for gesture recognition, motion inputs or analytics.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     Player,
+///     actions!(Player[
+///         (
+///             Action::<Movement>::new(),
+///             ActionHistory::new(60),
+///             Bindings::spawn(Cardinal::wasd_keys()),
+///         ),
+///     ]),
+/// ));
+///
+/// fn dash(history: Single<&ActionHistory, With<Action<Movement>>>) {
+///     if let Some(peak) = history.peak(0.2)
+///         && peak.as_axis2d().length() >= 1.0
+///     {
+///         // ...
+///     }
+/// }
+/// # #[derive(Component)]
+/// # struct Player;
+/// # #[derive(InputAction)]
+/// # #[action_output(Vec2)]
+/// # struct Movement;
+/// ```
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+pub struct ActionHistory {
+    /// The type of time used for timestamps.
+    pub time_kind: TimeKind,
+
+    capacity: usize,
+
+    /// Recorded entries, from oldest to newest.
+    entries: VecDeque<HistoryEntry>,
+
+    /// State from the last push, tracked separately since entries may not be stored.
+    last_state: TriggerState,
+
+    /// Timestamp of the last push.
+    last_timestamp: Duration,
+
+    /// Timestamp of the last transition from [`TriggerState::None`].
+    start_timestamp: Option<Duration>,
+}
+
+impl ActionHistory {
+    /// Creates a new instance that keeps up to `capacity` last entries.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            time_kind: Default::default(),
+            capacity,
+            entries: VecDeque::with_capacity(capacity),
+            last_state: Default::default(),
+            last_timestamp: Duration::ZERO,
+            start_timestamp: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+
+    /// Records a new entry, discarding the oldest one if the capacity is reached.
+    pub fn push(&mut self, value: ActionValue, state: TriggerState, timestamp: Duration) {
+        if self.last_state == TriggerState::None && state != TriggerState::None {
+            self.start_timestamp = Some(timestamp);
+        }
+        self.last_state = state;
+        self.last_timestamp = timestamp;
+
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(HistoryEntry {
+            value,
+            state,
+            timestamp,
+        });
+    }
+
+    /// Removes all entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.last_state = TriggerState::None;
+        self.start_timestamp = None;
+    }
+
+    /// Returns the maximum number of stored entries.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of stored entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterates over entries from oldest to newest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &HistoryEntry> + ExactSizeIterator {
+        self.entries.iter()
+    }
+
+    /// Returns the most recent entry.
+    #[must_use]
+    pub fn latest(&self) -> Option<&HistoryEntry> {
+        self.entries.back()
+    }
+
+    /// Returns the entry recorded `evaluations_ago` evaluations ago.
+    ///
+    /// `0` corresponds to [`Self::latest`].
+    #[must_use]
+    pub fn get(&self, evaluations_ago: usize) -> Option<&HistoryEntry> {
+        let index = self.entries.len().checked_sub(evaluations_ago + 1)?;
+        self.entries.get(index)
+    }
+
+    /// Iterates over entries recorded within the last `secs` seconds from newest to oldest.
+    ///
+    /// Time is measured relative to the last recorded evaluation.
+    pub fn within(&self, secs: f32) -> impl Iterator<Item = &HistoryEntry> {
+        let now = self.last_timestamp;
+        self.entries
+            .iter()
+            .rev()
+            .take_while(move |e| now.saturating_sub(e.timestamp).as_secs_f32() <= secs)
+    }
+
+    /// Returns the value with the largest magnitude recorded within the last `secs` seconds.
+    ///
+    /// See also [`Self::within`].
+    #[must_use]
+    pub fn peak(&self, secs: f32) -> Option<ActionValue> {
+        self.within(secs).map(|e| e.value).reduce(|peak, value| {
+            if value.as_axis3d().length_squared() > peak.as_axis3d().length_squared() {
+                value
+            } else {
+                peak
+            }
+        })
+    }
+
+    /// Returns the time in seconds since the action last transitioned from [`TriggerState::None`].
+    ///
+    /// Time is measured relative to the last recorded evaluation.
+    /// Tracked even if the entry with the transition was already discarded or the capacity is 0.
+    #[must_use]
+    pub fn secs_since_start(&self) -> Option<f32> {
+        let start = self.start_timestamp?;
+        Some(self.last_timestamp.saturating_sub(start).as_secs_f32())
+    }
+}
+
+/// Single record of [`ActionHistory`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Debug, PartialEq))]
+pub struct HistoryEntry {
+    /// Action value after the evaluation.
+    pub value: ActionValue,
+
+    /// Action state after the evaluation.
+    pub state: TriggerState,
+
+    /// Elapsed time of the clock from [`ActionHistory::time_kind`].
+    pub timestamp: Duration,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capacity() {
+        let mut history = ActionHistory::new(2);
+        history.push(1.0.into(), TriggerState::Fired, secs(0.0));
+        history.push(2.0.into(), TriggerState::Fired, secs(0.1));
+        history.push(3.0.into(), TriggerState::Fired, secs(0.2));
+
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.get(0).unwrap().value, 3.0.into());
+        assert_eq!(history.get(1).unwrap().value, 2.0.into());
+        assert!(history.get(2).is_none());
+    }
+
+    #[test]
+    fn peak() {
+        let mut history = ActionHistory::new(4);
+        history.push((-3.0).into(), TriggerState::Fired, secs(0.0));
+        history.push(2.0.into(), TriggerState::Fired, secs(0.5));
+        history.push((-1.0).into(), TriggerState::Fired, secs(0.75));
+        history.push(0.5.into(), TriggerState::Fired, secs(1.0));
+
+        assert_eq!(history.peak(0.6), Some(2.0.into()));
+        assert_eq!(history.peak(1.0), Some((-3.0).into()));
+        assert_eq!(history.peak(0.0), Some(0.5.into()));
+        assert_eq!(ActionHistory::new(1).peak(1.0), None);
+    }
+
+    #[test]
+    fn secs_since_start() {
+        let mut history = ActionHistory::new(1);
+        assert_eq!(history.secs_since_start(), None);
+
+        history.push(false.into(), TriggerState::None, secs(0.0));
+        assert_eq!(history.secs_since_start(), None);
+
+        history.push(true.into(), TriggerState::Ongoing, secs(0.5));
+        history.push(true.into(), TriggerState::Fired, secs(1.0));
+        history.push(true.into(), TriggerState::Fired, secs(2.0));
+        assert_eq!(history.secs_since_start(), Some(1.5));
+
+        history.push(false.into(), TriggerState::None, secs(3.0));
+        history.push(true.into(), TriggerState::Fired, secs(4.0));
+        assert_eq!(history.secs_since_start(), Some(0.0));
+    }
+
+    #[test]
+    fn secs_since_start_without_capacity() {
+        let mut history = ActionHistory::new(0);
+        history.push(true.into(), TriggerState::Fired, secs(1.0));
+        history.push(true.into(), TriggerState::Fired, secs(2.5));
+        assert!(history.is_empty());
+        assert_eq!(history.secs_since_start(), Some(1.5));
+    }
+
+    fn secs(secs: f64) -> Duration {
+        Duration::from_secs_f64(secs)
+    }
+}
@@ -0,0 +1,340 @@
+use bevy::prelude::*;
+
+use crate::{condition::sector, prelude::*};
+
+/// Headless radial selection driven by an [`Action<C>`] with a 2D value.
+///
+/// Opt-in, insert it on the action entity. The menu is open while the action state is not
+/// [`TriggerState::None`]. To open the menu with a separate action, like a held button,
+/// add [`Chord`] with that action. Once the action state returns to [`TriggerState::None`],
+/// the hovered slot becomes selected.
+///
+/// The circle is split into [`Self::slots`] equal slots using the same layout as [`Sector`]:
+/// slot `0` is centered on the positive X axis and the indices go counter-clockwise.
+///
+/// Updated during [`EnhancedInputSystems::Apply`] once per context evaluation,
+/// triggering [`RadialHover`] when the hovered slot changes and [`RadialSelect`] when a slot is selected.
+/// Doesn't depend on any UI, use [`Self::hovered`] and [`Self::selected`] to render it.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::{ecs::spawn::SpawnWith, prelude::*};
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut app = App::new();
+/// app.add_observer(select_weapon);
+///
+/// app.world_mut().spawn((
+///     Player,
+///     Actions::<Player>::spawn(SpawnWith(|context: &mut ActionSpawner<_>| {
+///         let open = context
+///             .spawn((Action::<OpenWheel>::new(), bindings![GamepadButton::LeftTrigger]))
+///             .id();
+///
+///         context.spawn((
+///             Action::<WeaponWheel>::new(),
+///             RadialMenu::new(8).with_clear_on_center(true),
+///             Chord::single(open),
+///             Bindings::spawn(Axial::right_stick()),
+///         ));
+///     })),
+/// ));
+///
+/// fn select_weapon(select: On<RadialSelect>, wheels: Query<(), With<Action<WeaponWheel>>>) {
+///     if wheels.contains(select.action) {
+///         info!("selected weapon {}", select.index);
+///     }
+/// }
+/// # #[derive(Component)]
+/// # struct Player;
+/// # #[derive(InputAction)]
+/// # #[action_output(bool)]
+/// # struct OpenWheel;
+/// # #[derive(InputAction)]
+/// # #[action_output(Vec2)]
+/// # struct WeaponWheel;
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, PartialEq)
+)]
+pub struct RadialMenu {
+    /// Number of slots.
+    pub slots: u32,
+
+    /// How the action value is interpreted.
+    pub input: RadialInput,
+
+    /// Magnitude threshold to hover a slot.
+    ///
+    /// By default set to `0.5`.
+    pub actuation: f32,
+
+    /// Width in radians of the band around slot borders that prevents switching
+    /// away from the hovered slot.
+    ///
+    /// By default set to `0.0`.
+    pub deadband: f32,
+
+    /// Whether to clear the hovered slot when the input drops below [`Self::actuation`].
+    ///
+    /// Useful for sticks to allow closing the menu without a selection
+    /// by letting the stick return to the center.
+    ///
+    /// By default set to `false`.
+    pub clear_on_center: bool,
+
+    open: bool,
+    cursor: Vec2,
+    hovered: Option<u32>,
+    selected: Option<u32>,
+}
+
+impl RadialMenu {
+    /// Creates a new instance with the given number of slots.
+    #[must_use]
+    pub fn new(slots: u32) -> Self {
+        Self {
+            slots,
+            input: Default::default(),
+            actuation: 0.5,
+            deadband: 0.0,
+            clear_on_center: false,
+            open: false,
+            cursor: Vec2::ZERO,
+            hovered: None,
+            selected: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_input(mut self, input: RadialInput) -> Self {
+        self.input = input;
+        self
+    }
+
+    #[must_use]
+    pub fn with_actuation(mut self, actuation: f32) -> Self {
+        self.actuation = actuation;
+        self
+    }
+
+    #[must_use]
+    pub fn with_deadband(mut self, deadband: f32) -> Self {
+        self.deadband = deadband;
+        self
+    }
+
+    #[must_use]
+    pub fn with_clear_on_center(mut self, clear_on_center: bool) -> Self {
+        self.clear_on_center = clear_on_center;
+        self
+    }
+
+    /// Returns `true` if the menu is currently open.
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Returns the direction used for hovering.
+    ///
+    /// For [`RadialInput::Absolute`] it's the last action value,
+    /// for [`RadialInput::Accumulated`] it's the accumulated position.
+    #[must_use]
+    pub fn cursor(&self) -> Vec2 {
+        self.cursor
+    }
+
+    /// Returns the currently hovered slot.
+    #[must_use]
+    pub fn hovered(&self) -> Option<u32> {
+        self.hovered
+    }
+
+    /// Returns the last selected slot.
+    #[must_use]
+    pub fn selected(&self) -> Option<u32> {
+        self.selected
+    }
+
+    /// Updates the menu from the action state and value.
+    ///
+    /// Returns the selected slot if the menu was closed with a hovered slot.
+    pub fn update(&mut self, state: TriggerState, value: ActionValue) -> Option<u32> {
+        if state == TriggerState::None {
+            if !self.open {
+                return None;
+            }
+
+            self.open = false;
+            let selected = self.hovered.take();
+            if selected.is_some() {
+                self.selected = selected;
+            }
+            return selected;
+        }
+
+        if !self.open {
+            self.open = true;
+            self.cursor = Vec2::ZERO;
+            self.hovered = None;
+        }
+
+        let value = value.as_axis2d();
+        self.cursor = match self.input {
+            RadialInput::Absolute => value,
+            RadialInput::Accumulated { radius } => (self.cursor + value).clamp_length_max(radius),
+        };
+
+        if self.slots == 0 || self.cursor.length_squared() < self.actuation * self.actuation {
+            if self.clear_on_center {
+                self.hovered = None;
+            }
+            return None;
+        }
+
+        let keep_hovered = self.hovered.is_some_and(|index| {
+            let angle = sector::angle_from_center(self.cursor, index, self.slots);
+            angle.abs() <= sector::half_width(self.slots) + self.deadband / 2.0
+        });
+        if !keep_hovered {
+            self.hovered = Some(Sector::sector_at(self.cursor, self.slots));
+        }
+
+        None
+    }
+}
+
+/// How [`RadialMenu`] interprets the action value.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Default, PartialEq)
+)]
+pub enum RadialInput {
+    /// Uses the value as the direction directly.
+    ///
+    /// Suitable for sticks.
+    #[default]
+    Absolute,
+    /// Accumulates the value into a position limited by the radius.
+    ///
+    /// Suitable for mouse motion. The position is reset when the menu opens.
+    /// Note that the mouse motion Y axis points down, so you might want to add [`Negate::y`].
+    Accumulated {
+        /// Maximum distance from the center.
+        radius: f32,
+    },
+}
+
+/// Triggers when the hovered slot of [`RadialMenu`] changes.
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct RadialHover {
+    /// Entity with the context component on which this event was triggered.
+    #[event_target]
+    pub context: Entity,
+
+    /// Action with the menu.
+    pub action: Entity,
+
+    /// Newly hovered slot.
+    pub index: Option<u32>,
+}
+
+/// Triggers when [`RadialMenu`] closes with a hovered slot.
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct RadialSelect {
+    /// Entity with the context component on which this event was triggered.
+    #[event_target]
+    pub context: Entity,
+
+    /// Action with the menu.
+    pub action: Entity,
+
+    /// Selected slot.
+    pub index: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f32::consts::PI;
+
+    use super::*;
+
+    #[test]
+    fn absolute() {
+        let mut menu = RadialMenu::new(4);
+
+        assert_eq!(menu.update(TriggerState::Fired, Vec2::ZERO.into()), None);
+        assert!(menu.is_open());
+        assert_eq!(menu.hovered(), None);
+
+        assert_eq!(menu.update(TriggerState::Fired, Vec2::Y.into()), None);
+        assert_eq!(menu.hovered(), Some(1));
+
+        assert_eq!(menu.update(TriggerState::Fired, Vec2::ZERO.into()), None);
+        assert_eq!(menu.hovered(), Some(1), "should keep the last hovered slot");
+
+        assert_eq!(menu.update(TriggerState::None, Vec2::ZERO.into()), Some(1));
+        assert!(!menu.is_open());
+        assert_eq!(menu.hovered(), None);
+        assert_eq!(menu.selected(), Some(1));
+
+        assert_eq!(menu.update(TriggerState::None, Vec2::ZERO.into()), None);
+    }
+
+    #[test]
+    fn clear_on_center() {
+        let mut menu = RadialMenu::new(4).with_clear_on_center(true);
+
+        menu.update(TriggerState::Fired, Vec2::NEG_X.into());
+        assert_eq!(menu.hovered(), Some(2));
+
+        menu.update(TriggerState::Fired, Vec2::ZERO.into());
+        assert_eq!(menu.hovered(), None);
+
+        assert_eq!(menu.update(TriggerState::None, Vec2::ZERO.into()), None);
+        assert_eq!(menu.selected(), None);
+    }
+
+    #[test]
+    fn accumulated() {
+        let mut menu = RadialMenu::new(8)
+            .with_input(RadialInput::Accumulated { radius: 100.0 })
+            .with_actuation(20.0);
+
+        menu.update(TriggerState::Fired, Vec2::new(10.0, 0.0).into());
+        assert_eq!(menu.hovered(), None);
+
+        menu.update(TriggerState::Fired, Vec2::new(15.0, 0.0).into());
+        assert_eq!(menu.hovered(), Some(0));
+
+        menu.update(TriggerState::Fired, Vec2::new(0.0, 500.0).into());
+        assert!((menu.cursor().length() - 100.0).abs() < 1e-3);
+        assert_eq!(menu.hovered(), Some(2));
+
+        assert_eq!(menu.update(TriggerState::None, Vec2::ZERO.into()), Some(2));
+
+        menu.update(TriggerState::Fired, Vec2::new(10.0, 0.0).into());
+        assert_eq!(menu.cursor(), Vec2::new(10.0, 0.0), "should reset on open");
+    }
+
+    #[test]
+    fn deadband() {
+        let mut menu = RadialMenu::new(4).with_deadband(0.4);
+
+        menu.update(TriggerState::Fired, Vec2::X.into());
+        assert_eq!(menu.hovered(), Some(0));
+
+        menu.update(TriggerState::Fired, Vec2::from_angle(PI / 4.0 + 0.1).into());
+        assert_eq!(menu.hovered(), Some(0));
+
+        menu.update(TriggerState::Fired, Vec2::from_angle(PI / 4.0 + 0.3).into());
+        assert_eq!(menu.hovered(), Some(1));
+    }
+}
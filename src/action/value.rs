@@ -185,6 +185,36 @@ impl From<Vec3> for ActionValue {
     }
 }
 
+impl From<i32> for ActionValue {
+    fn from(value: i32) -> Self {
+        ActionValue::Axis1D(value as f32)
+    }
+}
+
+impl From<IVec2> for ActionValue {
+    fn from(value: IVec2) -> Self {
+        ActionValue::Axis2D(value.as_vec2())
+    }
+}
+
+impl From<IVec3> for ActionValue {
+    fn from(value: IVec3) -> Self {
+        ActionValue::Axis3D(value.as_vec3())
+    }
+}
+
+impl From<BVec2> for ActionValue {
+    fn from(value: BVec2) -> Self {
+        ActionValue::Axis2D(Vec2::select(value, Vec2::ONE, Vec2::ZERO))
+    }
+}
+
+impl From<BVec3> for ActionValue {
+    fn from(value: BVec3) -> Self {
+        ActionValue::Axis3D(Vec3::select(value, Vec3::ONE, Vec3::ZERO))
+    }
+}
+
 impl From<(f32, f32)> for ActionValue {
     fn from(value: (f32, f32)) -> Self {
         ActionValue::Axis2D(value.into())
@@ -221,6 +251,47 @@ impl From<ActionValue> for Vec3 {
     }
 }
 
+/// Rounds to the nearest integer, with halfway cases rounded away from zero.
+impl From<ActionValue> for i32 {
+    fn from(value: ActionValue) -> Self {
+        ops::round(value.as_axis1d()) as i32
+    }
+}
+
+/// Rounds each axis to the nearest integer, with halfway cases rounded away from zero.
+impl From<ActionValue> for IVec2 {
+    fn from(value: ActionValue) -> Self {
+        let value = value.as_axis2d();
+        IVec2::new(ops::round(value.x) as i32, ops::round(value.y) as i32)
+    }
+}
+
+/// Rounds each axis to the nearest integer, with halfway cases rounded away from zero.
+impl From<ActionValue> for IVec3 {
+    fn from(value: ActionValue) -> Self {
+        let value = value.as_axis3d();
+        IVec3::new(
+            ops::round(value.x) as i32,
+            ops::round(value.y) as i32,
+            ops::round(value.z) as i32,
+        )
+    }
+}
+
+/// Sets each axis to `true` if it's non-zero.
+impl From<ActionValue> for BVec2 {
+    fn from(value: ActionValue) -> Self {
+        value.as_axis2d().cmpne(Vec2::ZERO)
+    }
+}
+
+/// Sets each axis to `true` if it's non-zero.
+impl From<ActionValue> for BVec3 {
+    fn from(value: ActionValue) -> Self {
+        value.as_axis3d().cmpne(Vec3::ZERO)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -269,4 +340,36 @@ mod tests {
         assert_eq!(value.convert(ActionValueDim::Axis2D), Vec2::ONE.into());
         assert_eq!(value.convert(ActionValueDim::Axis3D), Vec3::ONE.into());
     }
+
+    #[test]
+    fn integer_output() {
+        assert_eq!(i32::from(ActionValue::Axis1D(1.4)), 1);
+        assert_eq!(i32::from(ActionValue::Axis1D(-1.5)), -2);
+        assert_eq!(i32::from(ActionValue::Bool(true)), 1);
+        assert_eq!(
+            IVec2::from(ActionValue::Axis2D((0.6, -0.4).into())),
+            IVec2::new(1, 0)
+        );
+        assert_eq!(
+            IVec3::from(ActionValue::Axis3D((2.5, -0.5, 0.2).into())),
+            IVec3::new(3, -1, 0)
+        );
+        assert_eq!(ActionValue::from(IVec2::new(1, -1)), (1.0, -1.0).into());
+    }
+
+    #[test]
+    fn bool_vector_output() {
+        assert_eq!(
+            BVec2::from(ActionValue::Axis2D((0.0, -0.5).into())),
+            BVec2::new(false, true)
+        );
+        assert_eq!(
+            BVec3::from(ActionValue::Bool(true)),
+            BVec3::new(true, false, false)
+        );
+        assert_eq!(
+            ActionValue::from(BVec3::new(true, false, true)),
+            (1.0, 0.0, 1.0).into()
+        );
+    }
 }
@@ -0,0 +1,172 @@
+/*!
+Run conditions for systems based on action state.
+
+Similar to [`bevy::input::common_conditions`], but read [`ActionEvents`], [`TriggerState`]
+and [`ActionValue`] of actions instead of raw input.
+
+Each condition returns `true` if any action of type `A` satisfies it. Variants with the `_in` suffix
+additionally require the action to belong to the context `C`.
+
+Conditions reflect the last evaluation of the action's context. To avoid missing or repeating
+events, gate systems from the schedule in which the context is evaluated. For example, if
+the context is registered for [`FixedPreUpdate`], use the conditions for systems in [`FixedUpdate`].
+
+# Examples
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+let mut app = App::new();
+app.add_systems(
+    Update,
+    (
+        jump.run_if(action_started::<Jump>),
+        sprint.run_if(action_active_in::<Player, Sprint>),
+        zoom.run_if(action_value_above::<Zoom>(0.5)),
+    ),
+);
+# fn jump() {}
+# fn sprint() {}
+# fn zoom() {}
+# #[derive(Component)]
+# struct Player;
+# #[derive(InputAction)]
+# #[action_output(bool)]
+# struct Jump;
+# #[derive(InputAction)]
+# #[action_output(bool)]
+# struct Sprint;
+# #[derive(InputAction)]
+# #[action_output(f32)]
+# struct Zoom;
+```
+*/
+
+use bevy::{ecs::query::QueryFilter, prelude::*};
+
+use crate::prelude::*;
+
+/// Returns `true` if any action `A` has [`ActionEvents::START`].
+pub fn action_started<A: InputAction>(actions: Query<&ActionEvents, With<Action<A>>>) -> bool {
+    contains_events(&actions, ActionEvents::START)
+}
+
+/// Like [`action_started`], but only for actions from the context `C`.
+pub fn action_started_in<C: Component, A: InputAction>(
+    actions: Query<&ActionEvents, (With<Action<A>>, With<ActionOf<C>>)>,
+) -> bool {
+    contains_events(&actions, ActionEvents::START)
+}
+
+/// Returns `true` if any action `A` has [`ActionEvents::ONGOING`].
+pub fn action_ongoing<A: InputAction>(actions: Query<&ActionEvents, With<Action<A>>>) -> bool {
+    contains_events(&actions, ActionEvents::ONGOING)
+}
+
+/// Like [`action_ongoing`], but only for actions from the context `C`.
+pub fn action_ongoing_in<C: Component, A: InputAction>(
+    actions: Query<&ActionEvents, (With<Action<A>>, With<ActionOf<C>>)>,
+) -> bool {
+    contains_events(&actions, ActionEvents::ONGOING)
+}
+
+/// Returns `true` if any action `A` has [`ActionEvents::FIRE`].
+pub fn action_fired<A: InputAction>(actions: Query<&ActionEvents, With<Action<A>>>) -> bool {
+    contains_events(&actions, ActionEvents::FIRE)
+}
+
+/// Like [`action_fired`], but only for actions from the context `C`.
+pub fn action_fired_in<C: Component, A: InputAction>(
+    actions: Query<&ActionEvents, (With<Action<A>>, With<ActionOf<C>>)>,
+) -> bool {
+    contains_events(&actions, ActionEvents::FIRE)
+}
+
+/// Returns `true` if any action `A` has [`ActionEvents::CANCEL`].
+pub fn action_canceled<A: InputAction>(actions: Query<&ActionEvents, With<Action<A>>>) -> bool {
+    contains_events(&actions, ActionEvents::CANCEL)
+}
+
+/// Like [`action_canceled`], but only for actions from the context `C`.
+pub fn action_canceled_in<C: Component, A: InputAction>(
+    actions: Query<&ActionEvents, (With<Action<A>>, With<ActionOf<C>>)>,
+) -> bool {
+    contains_events(&actions, ActionEvents::CANCEL)
+}
+
+/// Returns `true` if any action `A` has [`ActionEvents::COMPLETE`].
+pub fn action_completed<A: InputAction>(actions: Query<&ActionEvents, With<Action<A>>>) -> bool {
+    contains_events(&actions, ActionEvents::COMPLETE)
+}
+
+/// Like [`action_completed`], but only for actions from the context `C`.
+pub fn action_completed_in<C: Component, A: InputAction>(
+    actions: Query<&ActionEvents, (With<Action<A>>, With<ActionOf<C>>)>,
+) -> bool {
+    contains_events(&actions, ActionEvents::COMPLETE)
+}
+
+/// Returns `true` if any action `A` has [`ActionEvents::VALUE_CHANGED`].
+///
+/// Requires [`ActionSettings::value_change_epsilon`] to be set.
+pub fn action_value_changed<A: InputAction>(
+    actions: Query<&ActionEvents, With<Action<A>>>,
+) -> bool {
+    contains_events(&actions, ActionEvents::VALUE_CHANGED)
+}
+
+/// Like [`action_value_changed`], but only for actions from the context `C`.
+pub fn action_value_changed_in<C: Component, A: InputAction>(
+    actions: Query<&ActionEvents, (With<Action<A>>, With<ActionOf<C>>)>,
+) -> bool {
+    contains_events(&actions, ActionEvents::VALUE_CHANGED)
+}
+
+/// Returns `true` if any action `A` is in [`TriggerState::Ongoing`] or [`TriggerState::Fired`].
+///
+/// Use [`action_fired`] if you need only [`TriggerState::Fired`].
+pub fn action_active<A: InputAction>(actions: Query<&TriggerState, With<Action<A>>>) -> bool {
+    actions.iter().any(|&state| state != TriggerState::None)
+}
+
+/// Like [`action_active`], but only for actions from the context `C`.
+pub fn action_active_in<C: Component, A: InputAction>(
+    actions: Query<&TriggerState, (With<Action<A>>, With<ActionOf<C>>)>,
+) -> bool {
+    actions.iter().any(|&state| state != TriggerState::None)
+}
+
+/// Returns `true` if the [`ActionValue`] magnitude of any action `A` is greater than `threshold`.
+///
+/// [`ActionValue::Bool`] is treated as `0.0` or `1.0`.
+/// Since the magnitude is never negative, a negative `threshold` returns `true` for any existing action.
+pub fn action_value_above<A: InputAction>(
+    threshold: f32,
+) -> impl FnMut(Query<&ActionValue, With<Action<A>>>) -> bool + Clone {
+    move |actions: Query<&ActionValue, With<Action<A>>>| value_above(&actions, threshold)
+}
+
+/// Like [`action_value_above`], but only for actions from the context `C`.
+pub fn action_value_above_in<C: Component, A: InputAction>(
+    threshold: f32,
+) -> impl FnMut(Query<&ActionValue, (With<Action<A>>, With<ActionOf<C>>)>) -> bool + Clone {
+    move |actions: Query<&ActionValue, (With<Action<A>>, With<ActionOf<C>>)>| {
+        value_above(&actions, threshold)
+    }
+}
+
+fn contains_events<F: QueryFilter>(
+    actions: &Query<&ActionEvents, F>,
+    events: ActionEvents,
+) -> bool {
+    actions
+        .iter()
+        .any(|action_events| action_events.contains(events))
+}
+
+fn value_above<F: QueryFilter>(actions: &Query<&ActionValue, F>, threshold: f32) -> bool {
+    actions
+        .iter()
+        .any(|value| value.as_axis3d().length() > threshold)
+}
@@ -10,6 +10,24 @@ order and update [`TriggerState`] on the associated action entity.
 If no conditions are attached, the action behaves like with [`Down`] condition with a zero actuation threshold,
 meaning it will trigger on any non-zero input value.
 
+To combine conditions with logical operations, such as "(Hold OR MultiTap) AND NOT Cooldown",
+use [composite conditions](logic).
+
+# Referenced actions
+
+Some conditions, like [`Chord`], [`BlockBy`] or [`Combo`], read the state of other actions.
+They report these actions via [`InputCondition::visit_dependencies`], and within a context,
+referenced actions are always evaluated first, regardless of their spawn order.
+
+Actions with more modifier keys in their bindings are still evaluated first to consume inputs
+for actions with fewer modifier keys, so the referenced action needs to have the same or higher
+number of modifier keys. If a referenced action belongs to another context, it's up to its context
+to be evaluated earlier: either by having a higher [`ContextPriority`] or by being registered to a
+schedule that runs earlier. Otherwise the condition reads the state from the previous evaluation of
+the referenced action, and a warning is logged once. The same applies to actions that reference each other.
+
+Referenced actions are collected when actions, their bindings or conditions are added or removed.
+
 # Examples
 
 ```
@@ -51,22 +69,33 @@ world.spawn((
 */
 
 pub mod block_by;
+pub mod charge;
 pub mod chord;
 pub mod combo;
 pub mod cooldown;
 pub mod down;
+pub mod erased;
 pub mod flick;
 pub mod fns;
 pub mod hold;
 pub mod hold_and_release;
+pub mod hysteresis;
+pub mod logic;
+pub mod motion_input;
+pub mod multi_tap;
 pub mod press;
 pub mod pulse;
 pub mod release;
+pub mod sector;
+pub mod stack;
+pub mod step_repeat;
 pub mod tap;
 pub mod toggle;
 
 use core::fmt::Debug;
 
+use bevy::prelude::*;
+
 use crate::prelude::*;
 
 /// Default actuation threshold for all conditions.
@@ -81,7 +110,8 @@ pub const DEFAULT_ACTUATION: f32 = 0.5;
 /// Can be attached both to bindings and actions.
 ///
 /// If you create a custom condition, it needs to be registered using
-/// [`InputConditionAppExt::add_input_condition`].
+/// [`InputConditionAppExt::add_input_condition`]. To make it serializable inside [`ConditionStack`]
+/// or [composite conditions](logic), also register [`ReflectInputCondition`] with `#[reflect(InputCondition)]`.
 pub trait InputCondition: Debug {
     /// Returns calculates state.
     ///
@@ -97,6 +127,19 @@ pub trait InputCondition: Debug {
     fn kind(&self) -> ConditionKind {
         ConditionKind::Explicit
     }
+
+    /// Calls `f` for each action whose state this condition reads from `actions`.
+    ///
+    /// Used to evaluate these actions before the action with this condition.
+    /// Called only when actions, their bindings or conditions are added or removed.
+    /// See [referenced actions](self#referenced-actions) for details.
+    fn visit_dependencies(&self, _f: &mut dyn FnMut(Entity)) {}
+
+    /// Reads data from the world before [`Self::evaluate`].
+    ///
+    /// Only resources and components registered with [`InputWorldAppExt`] are accessible.
+    /// Store the data you need in the condition and use it in [`Self::evaluate`].
+    fn read_world(&mut self, _world: &InputWorld) {}
 }
 
 /// Determines how a condition contributes to the final [`TriggerState`].
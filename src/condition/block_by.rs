@@ -42,7 +42,11 @@ use crate::prelude::*;
 /// struct Melee;
 /// ```
 #[derive(Component, Debug, Clone)]
-#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputCondition)
+)]
 pub struct BlockBy {
     /// Actions that block this action when they are firing.
     pub actions: SmallVec<[Entity; 2]>,
@@ -89,6 +93,10 @@ impl InputCondition for BlockBy {
     fn kind(&self) -> ConditionKind {
         ConditionKind::Blocker
     }
+
+    fn visit_dependencies(&self, f: &mut dyn FnMut(Entity)) {
+        self.actions.iter().copied().for_each(f);
+    }
 }
 
 #[cfg(test)]
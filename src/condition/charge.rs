@@ -0,0 +1,286 @@
+use bevy::prelude::*;
+
+use super::DEFAULT_ACTUATION;
+use crate::prelude::*;
+
+/**
+Charges while the input is actuated and fires on release.
+
+Acts as both a condition and a modifier. As a modifier, replaces the value with the charge fraction
+from `0.0` to `1.0` as [`ActionValue::Axis1D`]. As a condition, returns [`TriggerState::Ongoing`]
+while charging and [`TriggerState::Fired`] once on release, with the value set to the final charge.
+
+If released before reaching the minimum charge, returns [`TriggerState::None`] instead,
+which cancels the action.
+
+Optionally, the charge can decay if the input is held for too long after reaching the full charge.
+
+Since modifiers are applied before conditions, place modifiers that should affect the input before
+this component and modifiers that should affect the charge after it.
+
+# Examples
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+# let mut world = World::new();
+world.spawn((
+    Player,
+    actions!(Player[
+        (
+            Action::<DrawBow>::new(),
+            // Full charge in 1 second, cancels if released before 20%.
+            // Starts losing charge after holding for 2 more seconds.
+            Charge::new(1.0).with_min_charge(0.2).with_overcharge(2.0, 0.5),
+            bindings![MouseButton::Left],
+        ),
+    ]),
+));
+
+fn shoot(fire: On<Fire<DrawBow>>) {
+    info!("shot with {:.0}% power", fire.value * 100.0);
+}
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(f32)]
+struct DrawBow;
+```
+*/
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputCondition, InputModifier)
+)]
+pub struct Charge {
+    /// Time in seconds to reach the full charge.
+    pub charge_time: f32,
+
+    /// Minimum charge fraction required to fire on release.
+    ///
+    /// By default set to `0.0`.
+    pub min_charge: f32,
+
+    /// Time in seconds for which the full charge is kept before decaying.
+    ///
+    /// By default set to `0.0`.
+    pub overcharge_delay: f32,
+
+    /// Charge fraction lost per second after [`Self::overcharge_delay`].
+    ///
+    /// By default set to `0.0`, which disables the decay.
+    pub decay_rate: f32,
+
+    /// Trigger threshold.
+    pub actuation: f32,
+
+    /// The type of time used to advance the charge.
+    pub time_kind: TimeKind,
+
+    /// Time in seconds the input has been actuated.
+    elapsed: f32,
+
+    /// Current charge fraction.
+    charge: f32,
+
+    /// State calculated during the last transformation.
+    state: TriggerState,
+}
+
+impl Charge {
+    /// Creates a new instance with the given time in seconds to reach the full charge.
+    #[must_use]
+    pub fn new(charge_time: f32) -> Self {
+        Self {
+            charge_time,
+            min_charge: 0.0,
+            overcharge_delay: 0.0,
+            decay_rate: 0.0,
+            actuation: DEFAULT_ACTUATION,
+            time_kind: Default::default(),
+            elapsed: 0.0,
+            charge: 0.0,
+            state: TriggerState::None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_min_charge(mut self, min_charge: f32) -> Self {
+        self.min_charge = min_charge;
+        self
+    }
+
+    /// Sets [`Self::overcharge_delay`] and [`Self::decay_rate`].
+    #[must_use]
+    pub fn with_overcharge(mut self, delay: f32, decay_rate: f32) -> Self {
+        self.overcharge_delay = delay;
+        self.decay_rate = decay_rate;
+        self
+    }
+
+    #[must_use]
+    pub fn with_actuation(mut self, actuation: f32) -> Self {
+        self.actuation = actuation;
+        self
+    }
+
+    #[must_use]
+    pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+
+    /// Returns the current charge fraction.
+    #[must_use]
+    pub fn charge(&self) -> f32 {
+        self.charge
+    }
+
+    fn calculate_charge(&self) -> f32 {
+        if self.elapsed < self.charge_time {
+            return self.elapsed / self.charge_time;
+        }
+
+        let overcharge = self.elapsed - self.charge_time - self.overcharge_delay;
+        if overcharge > 0.0 {
+            (1.0 - overcharge * self.decay_rate).max(0.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+impl InputModifier for Charge {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        if value.is_actuated(self.actuation) {
+            self.elapsed += time.delta_kind(self.time_kind).as_secs_f32();
+            self.charge = self.calculate_charge();
+            self.state = TriggerState::Ongoing;
+            return self.charge.into();
+        }
+
+        let released = self.state == TriggerState::Ongoing;
+        let charge = self.charge;
+        self.elapsed = 0.0;
+        self.charge = 0.0;
+
+        if released && charge >= self.min_charge {
+            self.state = TriggerState::Fired;
+            charge.into()
+        } else {
+            self.state = TriggerState::None;
+            0.0.into()
+        }
+    }
+}
+
+impl InputCondition for Charge {
+    fn evaluate(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        _value: ActionValue,
+    ) -> TriggerState {
+        self.state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn charge() {
+        let (mut world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut charge = Charge::new(1.0);
+
+        assert_eq!(charge.transform(&actions, &time, true.into()), 0.0.into());
+        assert_eq!(
+            charge.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::Ongoing
+        );
+
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(500));
+        let (time, actions) = state.get(&world).unwrap();
+
+        assert_eq!(charge.transform(&actions, &time, true.into()), 0.5.into());
+        assert_eq!(
+            charge.evaluate(&actions, &time, 0.5.into()),
+            TriggerState::Ongoing
+        );
+
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_secs(1));
+        let (time, actions) = state.get(&world).unwrap();
+
+        assert_eq!(charge.transform(&actions, &time, true.into()), 1.0.into());
+        assert_eq!(charge.transform(&actions, &time, false.into()), 1.0.into());
+        assert_eq!(
+            charge.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::Fired
+        );
+
+        assert_eq!(charge.transform(&actions, &time, false.into()), 0.0.into());
+        assert_eq!(
+            charge.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::None
+        );
+    }
+
+    #[test]
+    fn min_charge() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(100));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut charge = Charge::new(1.0).with_min_charge(0.5);
+
+        charge.transform(&actions, &time, true.into());
+        assert_eq!(
+            charge.evaluate(&actions, &time, 0.1.into()),
+            TriggerState::Ongoing
+        );
+
+        assert_eq!(charge.transform(&actions, &time, false.into()), 0.0.into());
+        assert_eq!(
+            charge.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::None
+        );
+    }
+
+    #[test]
+    fn overcharge() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_secs(1));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut charge = Charge::new(1.0).with_overcharge(1.0, 0.5);
+
+        assert_eq!(charge.transform(&actions, &time, true.into()), 1.0.into());
+        assert_eq!(charge.transform(&actions, &time, true.into()), 1.0.into());
+        assert_eq!(charge.transform(&actions, &time, true.into()), 0.5.into());
+        assert_eq!(charge.transform(&actions, &time, true.into()), 0.0.into());
+        assert_eq!(charge.transform(&actions, &time, true.into()), 0.0.into());
+    }
+}
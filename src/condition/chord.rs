@@ -59,7 +59,11 @@ struct Heal;
 ```
 */
 #[derive(Component, Debug, Clone)]
-#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputCondition)
+)]
 pub struct Chord {
     /// Actions whose state will be inherited when they are firing.
     pub actions: SmallVec<[Entity; 2]>,
@@ -134,6 +138,10 @@ impl InputCondition for Chord {
     fn kind(&self) -> ConditionKind {
         ConditionKind::Implicit
     }
+
+    fn visit_dependencies(&self, f: &mut dyn FnMut(Entity)) {
+        self.actions.iter().copied().for_each(f);
+    }
 }
 
 #[cfg(test)]
@@ -17,6 +17,8 @@ Once all steps are completed, returns [`TriggerState::Fired`] once, then resets.
 Requires using [`SpawnRelated::spawn`] or separate spawning with [`ActionOf`]/[`BindingOf`]
 because you need to pass [`Entity`] for step and cancel actions.
 
+For repeated taps of a single input, consider using [`MultiTap`] instead.
+
 # Examples
 
 Double click:
@@ -56,7 +58,7 @@ struct Menu;
 #[cfg_attr(
     feature = "reflect",
     derive(Reflect),
-    reflect(Clone, Component, Debug, Default)
+    reflect(Clone, Component, Debug, Default, InputCondition)
 )]
 pub struct Combo {
     /// Ordered sequence of steps that define the combo.
@@ -212,6 +214,15 @@ impl InputCondition for Combo {
     fn kind(&self) -> ConditionKind {
         ConditionKind::Implicit
     }
+
+    fn visit_dependencies(&self, f: &mut dyn FnMut(Entity)) {
+        for step in &self.steps {
+            f(step.action);
+        }
+        for cancel_action in &self.cancel_actions {
+            f(cancel_action.action);
+        }
+    }
 }
 
 /// An action with associated events that progress [`Combo`].
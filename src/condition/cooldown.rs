@@ -2,17 +2,23 @@ use core::time::Duration;
 
 use bevy::prelude::*;
 
-use super::DEFAULT_ACTUATION;
+use super::{DEFAULT_ACTUATION, hysteresis};
 use crate::prelude::*;
 
 /// Returns [`TriggerState::Fired`] when actuated, then [`TriggerState::None`]
 /// on subsequent actuations until the cooldown duration has elapsed.
 #[derive(Component, Debug, Clone)]
-#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputCondition)
+)]
 pub struct Cooldown {
     /// Trigger threshold.
     pub actuation: f32,
 
+    hysteresis: Option<Hysteresis>,
+
     /// The type of time used to advance the timer.
     pub time_kind: TimeKind,
 
@@ -29,6 +35,7 @@ impl Cooldown {
         timer.tick(Duration::from_secs_f32(duration)); // Allow the first press to fire immediately.
         Self {
             actuation: DEFAULT_ACTUATION,
+            hysteresis: None,
             time_kind: Default::default(),
             timer,
             actuated: false,
@@ -41,6 +48,19 @@ impl Cooldown {
         self
     }
 
+    /// Replaces [`Self::actuation`] with separate press and release thresholds.
+    #[must_use]
+    pub fn with_hysteresis(mut self, hysteresis: Hysteresis) -> Self {
+        self.hysteresis = Some(hysteresis);
+        self
+    }
+
+    /// Returns the thresholds set by [`Self::with_hysteresis`].
+    #[must_use]
+    pub fn hysteresis(&self) -> Option<&Hysteresis> {
+        self.hysteresis.as_ref()
+    }
+
     #[must_use]
     pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
         self.time_kind = kind;
@@ -62,7 +82,7 @@ impl InputCondition for Cooldown {
         value: ActionValue,
     ) -> TriggerState {
         let last_actuated = self.actuated;
-        self.actuated = value.is_actuated(self.actuation);
+        self.actuated = hysteresis::is_actuated(&mut self.hysteresis, self.actuation, time, value);
 
         if !self.actuated {
             let finished_before = self.timer.is_finished();
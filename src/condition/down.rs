@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use super::DEFAULT_ACTUATION;
+use super::{DEFAULT_ACTUATION, hysteresis};
 use crate::prelude::*;
 
 /// Returns [`TriggerState::Fired`] when the input exceeds the actuation threshold.
@@ -8,17 +8,35 @@ use crate::prelude::*;
 #[cfg_attr(
     feature = "reflect",
     derive(Reflect),
-    reflect(Clone, Component, Debug, Default)
+    reflect(Clone, Component, Debug, Default, InputCondition)
 )]
 pub struct Down {
     /// Trigger threshold.
     pub actuation: f32,
+
+    hysteresis: Option<Hysteresis>,
 }
 
 impl Down {
     #[must_use]
     pub const fn new(actuation: f32) -> Self {
-        Self { actuation }
+        Self {
+            actuation,
+            hysteresis: None,
+        }
+    }
+
+    /// Replaces [`Self::actuation`] with separate press and release thresholds.
+    #[must_use]
+    pub fn with_hysteresis(mut self, hysteresis: Hysteresis) -> Self {
+        self.hysteresis = Some(hysteresis);
+        self
+    }
+
+    /// Returns the thresholds set by [`Self::with_hysteresis`].
+    #[must_use]
+    pub fn hysteresis(&self) -> Option<&Hysteresis> {
+        self.hysteresis.as_ref()
     }
 }
 
@@ -32,10 +50,10 @@ impl InputCondition for Down {
     fn evaluate(
         &mut self,
         _actions: &ActionsQuery,
-        _time: &ContextTime,
+        time: &ContextTime,
         value: ActionValue,
     ) -> TriggerState {
-        if value.is_actuated(self.actuation) {
+        if hysteresis::is_actuated(&mut self.hysteresis, self.actuation, time, value) {
             TriggerState::Fired
         } else {
             TriggerState::None
@@ -0,0 +1,187 @@
+use alloc::boxed::Box;
+use core::{
+    any::Any,
+    fmt::{self, Debug, Formatter},
+    ops::{Deref, DerefMut},
+};
+
+#[cfg(all(feature = "reflect", feature = "serialize"))]
+use bevy::reflect::{
+    TypeRegistry,
+    serde::{
+        DeserializeWithRegistry, ReflectDeserializeWithRegistry, ReflectSerializeWithRegistry,
+        SerializeWithRegistry,
+    },
+};
+use bevy::{prelude::*, reflect::FromType};
+#[cfg(all(feature = "reflect", feature = "serialize"))]
+use serde::{Deserializer, Serializer};
+
+use crate::prelude::*;
+#[cfg(all(feature = "reflect", feature = "serialize"))]
+use crate::type_registry;
+
+/// A type-erased [`InputCondition`].
+///
+/// Allows storing conditions of different types in a single component,
+/// such as [`ConditionStack`] or [composite conditions](super::logic).
+/// Dereferences to the condition as the trait object.
+///
+/// With the `reflect` and `serialize` features, can be serialized through reflection using the app's
+/// type registry, such as with [`TypedReflectSerializer`](bevy::reflect::serde::TypedReflectSerializer)
+/// or in scenes. Serialized as a map from the condition type path to its reflected value,
+/// so the type needs to be registered with [`ReflectInputCondition`] using `#[reflect(InputCondition)]`.
+/// All built-in conditions are registered this way.
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(opaque, Clone, Debug))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(SerializeWithRegistry, DeserializeWithRegistry)
+)]
+pub struct ErasedCondition(Box<dyn DynCondition>);
+
+impl ErasedCondition {
+    /// Creates a new instance from a condition.
+    #[must_use]
+    pub fn new<C: InputCondition + Clone + Send + Sync + 'static>(condition: C) -> Self {
+        Self(Box::new(condition))
+    }
+
+    /// Returns the condition if it has type `C`.
+    #[must_use]
+    pub fn downcast_ref<C: InputCondition + 'static>(&self) -> Option<&C> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Returns the condition if it has type `C`.
+    #[must_use]
+    pub fn downcast_mut<C: InputCondition + 'static>(&mut self) -> Option<&mut C> {
+        (&mut *self.0 as &mut dyn Any).downcast_mut()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &*self.0
+    }
+}
+
+impl<C: InputCondition + Clone + Send + Sync + 'static> From<C> for ErasedCondition {
+    fn from(condition: C) -> Self {
+        Self::new(condition)
+    }
+}
+
+impl Deref for ErasedCondition {
+    type Target = dyn InputCondition;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.0
+    }
+}
+
+impl DerefMut for ErasedCondition {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut *self.0
+    }
+}
+
+impl Clone for ErasedCondition {
+    fn clone(&self) -> Self {
+        Self(self.0.clone_boxed())
+    }
+}
+
+impl Debug for ErasedCondition {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(all(feature = "reflect", feature = "serialize"))]
+impl SerializeWithRegistry for ErasedCondition {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+        registry: &TypeRegistry,
+    ) -> Result<S::Ok, S::Error> {
+        type_registry::serialize(
+            serializer,
+            registry,
+            Any::type_id(&*self.0),
+            self.0.type_name(),
+            |reflect: &ReflectInputCondition| reflect.as_reflect(self),
+        )
+    }
+}
+
+#[cfg(all(feature = "reflect", feature = "serialize"))]
+impl<'de> DeserializeWithRegistry<'de> for ErasedCondition {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+        registry: &TypeRegistry,
+    ) -> Result<Self, D::Error> {
+        type_registry::deserialize(
+            deserializer,
+            registry,
+            |reflect: &ReflectInputCondition, value| reflect.from_reflect(value).ok(),
+        )
+    }
+}
+
+/// Object-safe extension of [`InputCondition`] to clone and downcast the erased condition.
+trait DynCondition: InputCondition + Any + Send + Sync {
+    fn clone_boxed(&self) -> Box<dyn DynCondition>;
+
+    #[cfg(all(feature = "reflect", feature = "serialize"))]
+    fn type_name(&self) -> &'static str;
+}
+
+impl<C: InputCondition + Clone + Send + Sync + 'static> DynCondition for C {
+    fn clone_boxed(&self) -> Box<dyn DynCondition> {
+        Box::new(self.clone())
+    }
+
+    #[cfg(all(feature = "reflect", feature = "serialize"))]
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<C>()
+    }
+}
+
+/// Type data to convert a reflected condition to and from [`ErasedCondition`].
+///
+/// Register it with `#[reflect(InputCondition)]` to make the condition serializable
+/// inside [`ConditionStack`] and [composite conditions](super::logic).
+#[derive(Clone)]
+pub struct ReflectInputCondition {
+    as_reflect: fn(&ErasedCondition) -> Option<&dyn Reflect>,
+    from_reflect: fn(Box<dyn Reflect>) -> Result<ErasedCondition, Box<dyn Reflect>>,
+}
+
+impl ReflectInputCondition {
+    /// Returns the condition as [`Reflect`] if it has the type this data was created for.
+    #[must_use]
+    pub fn as_reflect<'a>(&self, condition: &'a ErasedCondition) -> Option<&'a dyn Reflect> {
+        (self.as_reflect)(condition)
+    }
+
+    /// Converts a reflected condition into [`ErasedCondition`].
+    ///
+    /// Returns the value back if it doesn't have the type this data was created for.
+    pub fn from_reflect(
+        &self,
+        reflect: Box<dyn Reflect>,
+    ) -> Result<ErasedCondition, Box<dyn Reflect>> {
+        (self.from_reflect)(reflect)
+    }
+}
+
+impl<C: InputCondition + Reflect + Clone> FromType<C> for ReflectInputCondition {
+    fn from_type() -> Self {
+        Self {
+            as_reflect: |condition| {
+                condition
+                    .downcast_ref::<C>()
+                    .map(|condition| condition as &dyn Reflect)
+            },
+            from_reflect: |reflect| reflect.downcast::<C>().map(|condition| (*condition).into()),
+        }
+    }
+}
@@ -8,7 +8,11 @@ use crate::prelude::*;
 /// [`TriggerState::Ongoing`] when between the rest and actuation threshold
 /// and the flick timer hasn't finished
 #[derive(Component, Debug, Clone)]
-#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputCondition)
+)]
 pub struct Flick {
     /// The threshold the input must reach to activate.
     pub actuation: f32,
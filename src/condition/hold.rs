@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use super::DEFAULT_ACTUATION;
+use super::{DEFAULT_ACTUATION, hysteresis};
 use crate::prelude::*;
 
 /// Returns [`TriggerState::Ongoing`] when the input becomes actuated and
@@ -9,7 +9,11 @@ use crate::prelude::*;
 /// Returns [`TriggerState::None`] when the input stops being actuated earlier than the defined hold time.
 /// May optionally fire once, or repeatedly fire.
 #[derive(Component, Debug, Clone)]
-#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputCondition)
+)]
 pub struct Hold {
     /// Should this trigger fire only once, or fire every frame once the hold time threshold is met?
     pub one_shot: bool,
@@ -17,6 +21,8 @@ pub struct Hold {
     /// Trigger threshold.
     pub actuation: f32,
 
+    hysteresis: Option<Hysteresis>,
+
     /// The type of time used to advance the timer.
     pub time_kind: TimeKind,
 
@@ -30,6 +36,7 @@ impl Hold {
         Self {
             one_shot: false,
             actuation: DEFAULT_ACTUATION,
+            hysteresis: None,
             time_kind: Default::default(),
             timer: Timer::from_seconds(hold_time, TimerMode::Once),
         }
@@ -47,6 +54,19 @@ impl Hold {
         self
     }
 
+    /// Replaces [`Self::actuation`] with separate press and release thresholds.
+    #[must_use]
+    pub fn with_hysteresis(mut self, hysteresis: Hysteresis) -> Self {
+        self.hysteresis = Some(hysteresis);
+        self
+    }
+
+    /// Returns the thresholds set by [`Self::with_hysteresis`].
+    #[must_use]
+    pub fn hysteresis(&self) -> Option<&Hysteresis> {
+        self.hysteresis.as_ref()
+    }
+
     #[must_use]
     pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
         self.time_kind = kind;
@@ -67,7 +87,7 @@ impl InputCondition for Hold {
         time: &ContextTime,
         value: ActionValue,
     ) -> TriggerState {
-        let actuated = value.is_actuated(self.actuation);
+        let actuated = hysteresis::is_actuated(&mut self.hysteresis, self.actuation, time, value);
         if actuated {
             self.timer.tick(time.delta_kind(self.time_kind));
         } else {
@@ -154,4 +174,26 @@ mod tests {
             TriggerState::None
         );
     }
+
+    #[test]
+    fn hysteresis() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(300));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = Hold::new(1.0).with_hysteresis(Hysteresis::new(0.6, 0.4));
+        for value in [0.65, 0.48, 0.55] {
+            assert_eq!(
+                condition.evaluate(&actions, &time, value.into()),
+                TriggerState::Ongoing,
+                "noise above the release threshold shouldn't reset the timer"
+            );
+        }
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.45.into()),
+            TriggerState::Fired
+        );
+    }
 }
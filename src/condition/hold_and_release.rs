@@ -8,7 +8,11 @@ use crate::prelude::*;
 ///
 /// Returns [`TriggerState::None`] when the input stops being actuated earlier than the defined hold time.
 #[derive(Component, Debug, Clone)]
-#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputCondition)
+)]
 pub struct HoldAndRelease {
     /// Trigger threshold.
     pub actuation: f32,
@@ -0,0 +1,271 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/**
+Actuation with separate press and release thresholds.
+
+With a single threshold, an analog input hovering around it, such as a half-pressed trigger,
+quickly switches between actuated and not actuated. Hysteresis starts the actuation
+only when the input exceeds [`Self::press_actuation`] and ends it only when the input
+drops below [`Self::release_actuation`].
+
+Optionally, a debounce duration can be set to ignore state changes that don't last long enough.
+
+Can be used as a standalone condition that returns [`TriggerState::Fired`] while actuated,
+or assigned to actuation-based conditions like [`Down`], [`Hold`], [`Tap`], [`Pulse`], [`Cooldown`]
+and others to replace their actuation threshold.
+
+# Examples
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+# let mut world = World::new();
+world.spawn((
+    Player,
+    actions!(Player[
+        (
+            Action::<Aim>::new(),
+            Hysteresis::new(0.6, 0.4),
+            bindings![GamepadButton::LeftTrigger2],
+        ),
+        (
+            Action::<Fire>::new(),
+            Pulse::new(0.2).with_hysteresis(Hysteresis::new(0.6, 0.4).with_debounce(0.05)),
+            bindings![GamepadButton::RightTrigger2],
+        ),
+    ]),
+));
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Aim;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Fire;
+```
+*/
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputCondition)
+)]
+pub struct Hysteresis {
+    /// Threshold the input needs to exceed to start the actuation.
+    pub press_actuation: f32,
+
+    /// Threshold the input needs to drop below to end the actuation.
+    ///
+    /// Should be less than or equal to [`Self::press_actuation`].
+    pub release_actuation: f32,
+
+    /// Time in seconds a new state needs to last before switching to it.
+    ///
+    /// By default set to `0.0`.
+    pub debounce: f32,
+
+    /// The type of time used to advance the debounce.
+    ///
+    /// By default set to [`TimeKind::Real`].
+    pub time_kind: TimeKind,
+
+    actuated: bool,
+
+    /// Time in seconds the input has been in the opposite state.
+    pending: f32,
+}
+
+impl Hysteresis {
+    #[must_use]
+    pub fn new(press_actuation: f32, release_actuation: f32) -> Self {
+        Self {
+            press_actuation,
+            release_actuation,
+            debounce: 0.0,
+            time_kind: Default::default(),
+            actuated: false,
+            pending: 0.0,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_debounce(mut self, debounce: f32) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+
+    /// Returns `true` if the input is currently considered actuated.
+    #[must_use]
+    pub fn is_actuated(&self) -> bool {
+        self.actuated
+    }
+
+    /// Updates the state with the new input value and returns whether it's actuated.
+    pub fn update(&mut self, time: &ContextTime, value: ActionValue) -> bool {
+        let actuated = if self.actuated {
+            value.is_actuated(self.release_actuation)
+        } else {
+            value.is_actuated(self.press_actuation)
+        };
+
+        if actuated == self.actuated {
+            self.pending = 0.0;
+        } else {
+            self.pending += time.delta_kind(self.time_kind).as_secs_f32();
+            if self.pending >= self.debounce {
+                self.actuated = actuated;
+                self.pending = 0.0;
+            }
+        }
+
+        self.actuated
+    }
+}
+
+impl InputCondition for Hysteresis {
+    fn evaluate(
+        &mut self,
+        _actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> TriggerState {
+        if self.update(time, value) {
+            TriggerState::Fired
+        } else {
+            TriggerState::None
+        }
+    }
+}
+
+/// Returns whether the input is actuated using hysteresis if present
+/// or the actuation threshold otherwise.
+pub(super) fn is_actuated(
+    hysteresis: &mut Option<Hysteresis>,
+    actuation: f32,
+    time: &ContextTime,
+    value: ActionValue,
+) -> bool {
+    match hysteresis {
+        Some(hysteresis) => hysteresis.update(time, value),
+        None => value.is_actuated(actuation),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn thresholds() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = Hysteresis::new(0.6, 0.4);
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.5.into()),
+            TriggerState::None
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.6.into()),
+            TriggerState::Fired
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.5.into()),
+            TriggerState::Fired,
+            "should stay actuated above the release threshold"
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.3.into()),
+            TriggerState::None
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.5.into()),
+            TriggerState::None,
+            "should stay released below the press threshold"
+        );
+    }
+
+    #[test]
+    fn noisy_trigger() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut down = Down::new(0.5);
+        let mut condition = Down::new(0.5).with_hysteresis(Hysteresis::new(0.6, 0.4));
+
+        let signal = [0.45, 0.55, 0.48, 0.62, 0.49, 0.53, 0.45, 0.51, 0.38, 0.55];
+        let mut down_changes = 0;
+        let mut changes = 0;
+        let mut last_down = TriggerState::None;
+        let mut last = TriggerState::None;
+        for value in signal {
+            let state = down.evaluate(&actions, &time, value.into());
+            if state != last_down {
+                down_changes += 1;
+                last_down = state;
+            }
+
+            let state = condition.evaluate(&actions, &time, value.into());
+            if state != last {
+                changes += 1;
+                last = state;
+            }
+        }
+
+        assert_eq!(down_changes, 9, "single threshold should chatter");
+        assert_eq!(changes, 2, "should press once and release once");
+    }
+
+    #[test]
+    fn debounce() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(30));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = Hysteresis::new(0.5, 0.5).with_debounce(0.05);
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::None
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::None,
+            "spike shorter than the debounce should be ignored"
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::None
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::Fired
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::Fired
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::Fired,
+            "dip shorter than the debounce should be ignored"
+        );
+    }
+}
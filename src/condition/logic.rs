@@ -0,0 +1,368 @@
+/*!
+Conditions that combine other conditions with logical operations.
+
+Since conditions are components, an entity can have only one condition of each type, and
+[`ConditionKind`] only defines how different conditions contribute to the final state.
+Composite conditions own their children, so they can express any tree, like
+"(Hold OR MultiTap) AND NOT Cooldown", and contain multiple conditions of the same type.
+
+Children are stored as [`ErasedCondition`]s and evaluated in their order on every evaluation, without
+short-circuiting, so their internal timers stay correct. [`InputCondition::kind`] of the children
+is ignored; only the composite itself is combined with other conditions on the entity.
+
+Children are used only as conditions. Types that also act as modifiers and update their state
+in [`InputModifier::transform`], such as [`Charge`] and [`StepRepeat`], never update when nested
+and aren't supported as children. Attach them to the action or binding directly instead.
+
+Composite conditions are registered by the plugin, and their children don't need to be registered.
+Since children are type-erased, the tree can be built at runtime or deserialized from data.
+See [`ErasedCondition`] for serialization requirements.
+
+# Examples
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+let mut world = World::new();
+world.spawn((
+    Player,
+    actions!(Player[
+        (
+            Action::<Attack>::new(),
+            AllConditions::default()
+                .with(
+                    AnyConditions::default()
+                        .with(Hold::new(1.0))
+                        .with(MultiTap::double(0.3)),
+                )
+                .with(NotCondition::new(Down::new(0.95))),
+            bindings![GamepadButton::RightTrigger2],
+        ),
+    ]),
+));
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Attack;
+```
+*/
+
+use alloc::vec::Vec;
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Returns the least significant [`TriggerState`] from all conditions.
+///
+/// In other words, returns [`TriggerState::Fired`] only if all conditions return it,
+/// and [`TriggerState::None`] if any condition returns it.
+///
+/// See the [module documentation](self) for more details.
+#[derive(Component, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, InputCondition)
+)]
+pub struct AllConditions(pub Vec<ErasedCondition>);
+
+impl AllConditions {
+    /// Adds a condition to the end of the list.
+    #[must_use]
+    pub fn with(mut self, condition: impl Into<ErasedCondition>) -> Self {
+        self.0.push(condition.into());
+        self
+    }
+}
+
+impl InputCondition for AllConditions {
+    fn evaluate(
+        &mut self,
+        actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> TriggerState {
+        let mut state = TriggerState::Fired;
+        for condition in &mut self.0 {
+            state = state.min(condition.evaluate(actions, time, value));
+        }
+        state
+    }
+
+    fn visit_dependencies(&self, f: &mut dyn FnMut(Entity)) {
+        for condition in &self.0 {
+            condition.visit_dependencies(f);
+        }
+    }
+
+    fn read_world(&mut self, world: &InputWorld) {
+        for condition in &mut self.0 {
+            condition.read_world(world);
+        }
+    }
+}
+
+/// Returns the most significant [`TriggerState`] from all conditions.
+///
+/// In other words, returns [`TriggerState::Fired`] if any condition returns it,
+/// and [`TriggerState::None`] only if all conditions return it.
+///
+/// See the [module documentation](self) for more details.
+#[derive(Component, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, InputCondition)
+)]
+pub struct AnyConditions(pub Vec<ErasedCondition>);
+
+impl AnyConditions {
+    /// Adds a condition to the end of the list.
+    #[must_use]
+    pub fn with(mut self, condition: impl Into<ErasedCondition>) -> Self {
+        self.0.push(condition.into());
+        self
+    }
+}
+
+impl InputCondition for AnyConditions {
+    fn evaluate(
+        &mut self,
+        actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> TriggerState {
+        let mut state = TriggerState::None;
+        for condition in &mut self.0 {
+            state = state.max(condition.evaluate(actions, time, value));
+        }
+        state
+    }
+
+    fn visit_dependencies(&self, f: &mut dyn FnMut(Entity)) {
+        for condition in &self.0 {
+            condition.visit_dependencies(f);
+        }
+    }
+
+    fn read_world(&mut self, world: &InputWorld) {
+        for condition in &mut self.0 {
+            condition.read_world(world);
+        }
+    }
+}
+
+/// Inverts the [`TriggerState`] of the condition.
+///
+/// [`TriggerState::Fired`] becomes [`TriggerState::None`] and vice versa.
+/// [`TriggerState::Ongoing`] is left unchanged.
+///
+/// See the [module documentation](self) for more details.
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputCondition)
+)]
+pub struct NotCondition(pub ErasedCondition);
+
+impl NotCondition {
+    /// Creates a new instance for the condition.
+    #[must_use]
+    pub fn new(condition: impl Into<ErasedCondition>) -> Self {
+        Self(condition.into())
+    }
+}
+
+impl InputCondition for NotCondition {
+    fn evaluate(
+        &mut self,
+        actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> TriggerState {
+        match self.0.evaluate(actions, time, value) {
+            TriggerState::None => TriggerState::Fired,
+            TriggerState::Ongoing => TriggerState::Ongoing,
+            TriggerState::Fired => TriggerState::None,
+        }
+    }
+
+    fn visit_dependencies(&self, f: &mut dyn FnMut(Entity)) {
+        self.0.visit_dependencies(f);
+    }
+
+    fn read_world(&mut self, world: &InputWorld) {
+        self.0.read_world(world);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn all_of() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = AllConditions::default()
+            .with(Down::new(0.5))
+            .with(Down::new(1.0));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::Fired,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.5.into()),
+            TriggerState::None,
+        );
+
+        let mut condition = AllConditions::default()
+            .with(Down::default())
+            .with(Hold::new(1.0));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::Ongoing,
+        );
+    }
+
+    #[test]
+    fn any_of() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = AnyConditions::default()
+            .with(Down::new(1.0))
+            .with(Hold::new(1.0));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.5.into()),
+            TriggerState::Ongoing,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::Fired,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::None,
+        );
+    }
+
+    #[test]
+    fn not() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = NotCondition::new(Down::default());
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::None,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::Fired,
+        );
+
+        let mut condition = NotCondition::new(Hold::new(1.0));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::Ongoing,
+        );
+    }
+
+    #[test]
+    fn evaluates_all() {
+        let (mut world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = AnyConditions::default()
+            .with(Down::default())
+            .with(Hold::new(1.0));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::Fired,
+        );
+
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_secs(1));
+        let (time, actions) = state.get(&world).unwrap();
+
+        condition.evaluate(&actions, &time, 1.0.into());
+        let hold = condition.0[1].downcast_ref::<Hold>().unwrap();
+        assert!(
+            hold.timer().is_finished(),
+            "timer should tick even if the first condition fires"
+        );
+    }
+
+    #[test]
+    fn stateful_children() {
+        let (mut world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = AllConditions::default()
+            .with(Hold::new(1.0))
+            .with(Charge::new(1.0));
+        condition.evaluate(&actions, &time, 1.0.into());
+
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_secs(1));
+        let (time, actions) = state.get(&world).unwrap();
+
+        condition.evaluate(&actions, &time, 1.0.into());
+        let hold = condition.0[0].downcast_ref::<Hold>().unwrap();
+        assert!(hold.timer().is_finished(), "nested timers should tick");
+
+        condition.evaluate(&actions, &time, 0.0.into());
+        let hold = condition.0[0].downcast_ref::<Hold>().unwrap();
+        assert_eq!(
+            hold.timer().elapsed_secs(),
+            0.0,
+            "nested timers should reset on release"
+        );
+
+        let charge = condition.0[1].downcast_ref::<Charge>().unwrap();
+        assert_eq!(
+            charge.charge(),
+            0.0,
+            "charge is updated only when used as a modifier"
+        );
+    }
+
+    #[test]
+    fn nested() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = AllConditions::default()
+            .with(
+                AnyConditions::default()
+                    .with(Down::new(1.0))
+                    .with(Hold::new(1.0)),
+            )
+            .with(NotCondition::new(Down::new(2.0)));
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::Fired,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 2.0.into()),
+            TriggerState::None,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::None,
+        );
+    }
+}
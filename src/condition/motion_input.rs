@@ -0,0 +1,732 @@
+use alloc::vec::Vec;
+use core::{f32::consts::FRAC_PI_8, time::Duration};
+
+use bevy::prelude::*;
+use log::warn;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use super::DEFAULT_ACTUATION;
+use crate::prelude::*;
+
+/**
+Sequence of stick or d-pad directions, such as quarter-circle or dragon punch motions from fighting games.
+
+Evaluates the action value as [`ActionValue::Axis2D`], quantizes it into [`NumpadDirection`]
+and advances when the next direction of [`Self::motion`] is reached. Directions between steps
+are ignored, as long as the next one is reached within the step window. Holding the previous
+direction doesn't consume the window.
+
+While the first direction is charged and after the first step, returns [`TriggerState::Ongoing`]
+until the last step.
+Once all steps are completed, returns [`TriggerState::Fired`] once, then resets.
+
+If [`Self::button`] is set, the linked action also needs to trigger [`Self::button_events`]
+within the button window after the motion is completed. The button action is evaluated
+before this action, similar to [`Chord`].
+
+Directions are relative to the character facing: forward is positive X by default.
+Set [`Self::mirrored`] when the character faces negative X.
+
+# Examples
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+# let mut world = World::new();
+world.spawn((
+    Fighter,
+    Actions::<Fighter>::spawn(SpawnWith(|context: &mut ActionSpawner<_>| {
+        let punch = context
+            .spawn((Action::<Punch>::new(), bindings![GamepadButton::West]))
+            .id();
+
+        // Quarter-circle forward + punch.
+        context.spawn((
+            Action::<Fireball>::new(),
+            MotionInput::quarter_circle_forward().with_button(punch),
+            Bindings::spawn((Cardinal::dpad(), Axial::left_stick())),
+        ));
+
+        // Hold back for 1 second, then forward.
+        context.spawn((
+            Action::<Dash>::new(),
+            MotionInput::charge_back_forward(1.0),
+            Bindings::spawn((Cardinal::dpad(), Axial::left_stick())),
+        ));
+    })),
+));
+
+fn update_facing(fighter: Single<&Transform, With<Fighter>>, mut motions: Query<&mut MotionInput>) {
+    for mut motion in &mut motions {
+        motion.mirrored = fighter.scale.x < 0.0;
+    }
+}
+
+#[derive(Component)]
+struct Fighter;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Punch;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Fireball;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Dash;
+```
+*/
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputCondition)
+)]
+pub struct MotionInput {
+    /// Directions that need to be reached in order.
+    pub motion: Vec<NumpadDirection>,
+
+    /// Action that needs to trigger [`Self::button_events`] after the motion is completed.
+    pub button: Option<Entity>,
+
+    /// Events for [`Self::button`] to complete the motion.
+    ///
+    /// By default set to [`ActionEvents::START`].
+    pub button_events: ActionEvents,
+
+    /// Minimum magnitude for the value to be considered a direction instead of
+    /// [`NumpadDirection::Neutral`].
+    pub dead_zone: f32,
+
+    /// Additional angle in radians by which each direction sector is extended when matching steps.
+    ///
+    /// Without tolerance, each sector spans 45 degrees. Positive values make adjacent
+    /// sectors overlap, making the motion more lenient.
+    ///
+    /// By default set to [`FRAC_PI_8`] divided by 2.
+    pub tolerance: f32,
+
+    /// Mirrors the X axis, swapping forward and back.
+    pub mirrored: bool,
+
+    /// The type of time used to advance the timers.
+    pub time_kind: TimeKind,
+
+    /// Tracks how long the first direction is held.
+    charge_timer: Timer,
+
+    /// Tracks the window for reaching the next direction.
+    step_timer: Timer,
+
+    /// Tracks the window for triggering the button after the motion.
+    button_timer: Timer,
+
+    /// Number of reached directions.
+    progress: usize,
+}
+
+impl MotionInput {
+    /// Creates a new instance for the given sequence of directions.
+    ///
+    /// The step window is set to 0.2 seconds and the button window to 0.15 seconds.
+    #[must_use]
+    pub fn new(motion: impl IntoIterator<Item = NumpadDirection>) -> Self {
+        Self {
+            motion: motion.into_iter().collect(),
+            button: None,
+            button_events: ActionEvents::START,
+            dead_zone: DEFAULT_ACTUATION,
+            tolerance: FRAC_PI_8 / 2.0,
+            mirrored: false,
+            time_kind: Default::default(),
+            charge_timer: Timer::new(Duration::ZERO, TimerMode::Once),
+            step_timer: Timer::from_seconds(0.2, TimerMode::Once),
+            button_timer: Timer::from_seconds(0.15, TimerMode::Once),
+            progress: 0,
+        }
+    }
+
+    /// Creates a quarter-circle forward motion (236).
+    #[must_use]
+    pub fn quarter_circle_forward() -> Self {
+        Self::new([
+            NumpadDirection::Down,
+            NumpadDirection::DownForward,
+            NumpadDirection::Forward,
+        ])
+    }
+
+    /// Creates a quarter-circle back motion (214).
+    #[must_use]
+    pub fn quarter_circle_back() -> Self {
+        Self::new([
+            NumpadDirection::Down,
+            NumpadDirection::DownBack,
+            NumpadDirection::Back,
+        ])
+    }
+
+    /// Creates a dragon punch motion (623).
+    #[must_use]
+    pub fn dragon_punch() -> Self {
+        Self::new([
+            NumpadDirection::Forward,
+            NumpadDirection::Down,
+            NumpadDirection::DownForward,
+        ])
+    }
+
+    /// Creates a half-circle forward motion (41236).
+    #[must_use]
+    pub fn half_circle_forward() -> Self {
+        Self::new([
+            NumpadDirection::Back,
+            NumpadDirection::DownBack,
+            NumpadDirection::Down,
+            NumpadDirection::DownForward,
+            NumpadDirection::Forward,
+        ])
+    }
+
+    /// Creates a half-circle back motion (63214).
+    #[must_use]
+    pub fn half_circle_back() -> Self {
+        Self::new([
+            NumpadDirection::Forward,
+            NumpadDirection::DownForward,
+            NumpadDirection::Down,
+            NumpadDirection::DownBack,
+            NumpadDirection::Back,
+        ])
+    }
+
+    /// Creates a charge motion that requires holding back for `charge_time` seconds, then forward (\[4\]6).
+    #[must_use]
+    pub fn charge_back_forward(charge_time: f32) -> Self {
+        Self::new([NumpadDirection::Back, NumpadDirection::Forward]).with_charge_time(charge_time)
+    }
+
+    /// Creates a charge motion that requires holding down for `charge_time` seconds, then up (\[2\]8).
+    #[must_use]
+    pub fn charge_down_up(charge_time: f32) -> Self {
+        Self::new([NumpadDirection::Down, NumpadDirection::Up]).with_charge_time(charge_time)
+    }
+
+    /// Sets [`Self::button`].
+    #[must_use]
+    pub fn with_button(mut self, action: Entity) -> Self {
+        self.button = Some(action);
+        self
+    }
+
+    /// Sets [`Self::button_events`].
+    #[must_use]
+    pub fn with_button_events(mut self, events: ActionEvents) -> Self {
+        self.button_events = events;
+        self
+    }
+
+    #[must_use]
+    pub fn with_dead_zone(mut self, dead_zone: f32) -> Self {
+        self.dead_zone = dead_zone;
+        self
+    }
+
+    #[must_use]
+    pub fn with_tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    #[must_use]
+    pub fn with_mirrored(mut self, mirrored: bool) -> Self {
+        self.mirrored = mirrored;
+        self
+    }
+
+    #[must_use]
+    pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+
+    /// Sets the time in seconds for which the first direction needs to be held.
+    #[must_use]
+    pub fn with_charge_time(mut self, charge_time: f32) -> Self {
+        self.charge_timer = Timer::from_seconds(charge_time, TimerMode::Once);
+        self
+    }
+
+    /// Sets the time in seconds to reach the next direction after the previous one.
+    #[must_use]
+    pub fn with_step_window(mut self, step_window: f32) -> Self {
+        self.step_timer = Timer::from_seconds(step_window, TimerMode::Once);
+        self
+    }
+
+    /// Sets the time in seconds to trigger [`Self::button`] after the motion is completed.
+    #[must_use]
+    pub fn with_button_window(mut self, button_window: f32) -> Self {
+        self.button_timer = Timer::from_seconds(button_window, TimerMode::Once);
+        self
+    }
+
+    /// Returns the number of reached directions.
+    #[must_use]
+    pub fn progress(&self) -> usize {
+        self.progress
+    }
+
+    /// Returns the timer for holding the first direction.
+    #[must_use]
+    pub fn charge_timer(&self) -> &Timer {
+        &self.charge_timer
+    }
+
+    fn reset(&mut self) {
+        self.progress = 0;
+        self.charge_timer.reset();
+        self.step_timer.reset();
+        self.button_timer.reset();
+    }
+
+    fn matches(&self, direction: NumpadDirection, value: Vec2) -> bool {
+        direction.matches(value, self.dead_zone, self.tolerance)
+    }
+
+    /// Advances to the next step and returns `true` if the motion is completed.
+    fn advance(&mut self) -> bool {
+        self.progress += 1;
+        self.step_timer.reset();
+        self.button_timer.reset();
+        self.progress == self.motion.len()
+    }
+
+    fn button_triggered(&self, actions: &ActionsQuery) -> bool {
+        let Some(button) = self.button else {
+            return true;
+        };
+
+        let Ok((.., events, _)) = actions.get(button) else {
+            // TODO: use `warn_once` when `bevy_log` becomes `no_std` compatible.
+            warn!("`{button}` is not a valid action");
+            return false;
+        };
+
+        events.contains(self.button_events)
+    }
+}
+
+impl InputCondition for MotionInput {
+    fn evaluate(
+        &mut self,
+        actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> TriggerState {
+        if self.motion.is_empty() {
+            // TODO: use `warn_once` when `bevy_log` becomes `no_std` compatible.
+            warn!("motion has no directions");
+            return TriggerState::None;
+        }
+
+        let delta = time.delta_kind(self.time_kind);
+        let mut value = value.as_axis2d();
+        if self.mirrored {
+            value.x = -value.x;
+        }
+
+        if self.progress == self.motion.len() {
+            // Waiting for the button.
+            if self.button_triggered(actions) {
+                self.reset();
+                return TriggerState::Fired;
+            }
+
+            self.button_timer.tick(delta);
+            if !self.button_timer.is_finished() {
+                return TriggerState::Ongoing;
+            }
+
+            // We don't early-return since the first direction could be reached.
+            self.reset();
+        }
+
+        if self.progress == 0 {
+            if self.matches(self.motion[0], value) {
+                self.charge_timer.tick(delta);
+                if !self.charge_timer.is_finished() && !self.charge_timer.duration().is_zero() {
+                    // Still charging.
+                    return TriggerState::Ongoing;
+                }
+
+                if self.advance() && self.button_triggered(actions) {
+                    self.reset();
+                    return TriggerState::Fired;
+                }
+            } else {
+                self.charge_timer.reset();
+            }
+        } else if self.matches(self.motion[self.progress], value) {
+            if self.advance() && self.button_triggered(actions) {
+                self.reset();
+                return TriggerState::Fired;
+            }
+        } else if self.matches(self.motion[self.progress - 1], value) {
+            self.step_timer.reset();
+        } else {
+            self.step_timer.tick(delta);
+            if self.step_timer.is_finished() {
+                self.reset();
+            }
+        }
+
+        if self.progress > 0 {
+            TriggerState::Ongoing
+        } else {
+            TriggerState::None
+        }
+    }
+
+    fn visit_dependencies(&self, f: &mut dyn FnMut(Entity)) {
+        if let Some(button) = self.button {
+            f(button);
+        }
+    }
+}
+
+/// Direction in [numpad notation](https://glossary.infil.net/?t=Numpad%20Notation).
+///
+/// Forward corresponds to positive X and up to positive Y.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum NumpadDirection {
+    /// Down and back, 1 on the numpad.
+    DownBack = 1,
+    /// Down, 2 on the numpad.
+    Down = 2,
+    /// Down and forward, 3 on the numpad.
+    DownForward = 3,
+    /// Back, 4 on the numpad.
+    Back = 4,
+    /// No direction, 5 on the numpad.
+    ///
+    /// Matches when the value is inside the dead zone.
+    Neutral = 5,
+    /// Forward, 6 on the numpad.
+    Forward = 6,
+    /// Up and back, 7 on the numpad.
+    UpBack = 7,
+    /// Up, 8 on the numpad.
+    Up = 8,
+    /// Up and forward, 9 on the numpad.
+    UpForward = 9,
+}
+
+impl NumpadDirection {
+    /// Returns the direction for the given numpad digit.
+    #[must_use]
+    pub const fn from_digit(digit: u8) -> Option<Self> {
+        match digit {
+            1 => Some(Self::DownBack),
+            2 => Some(Self::Down),
+            3 => Some(Self::DownForward),
+            4 => Some(Self::Back),
+            5 => Some(Self::Neutral),
+            6 => Some(Self::Forward),
+            7 => Some(Self::UpBack),
+            8 => Some(Self::Up),
+            9 => Some(Self::UpForward),
+            _ => None,
+        }
+    }
+
+    /// Returns the numpad digit of this direction.
+    #[must_use]
+    pub const fn digit(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the unit vector of this direction or zero for [`Self::Neutral`].
+    #[must_use]
+    pub fn as_vec2(self) -> Vec2 {
+        let digit = self.digit() as i32 - 1;
+        let x = digit % 3 - 1;
+        let y = digit / 3 - 1;
+        IVec2::new(x, y).as_vec2().normalize_or_zero()
+    }
+
+    /// Quantizes the value into one of 8 directions, or [`Self::Neutral`] if
+    /// its magnitude is below `dead_zone`.
+    #[must_use]
+    pub fn quantize(value: Vec2, dead_zone: f32) -> Self {
+        if value.length_squared() < dead_zone * dead_zone || value == Vec2::ZERO {
+            return Self::Neutral;
+        }
+
+        // Each axis is considered active outside of the 45 degree sector around the other axis.
+        let threshold = ops::sin(FRAC_PI_8);
+        let value = value.normalize();
+        let axis = |value: f32| {
+            if value > threshold {
+                1
+            } else if value < -threshold {
+                -1
+            } else {
+                0
+            }
+        };
+
+        let digit = (axis(value.y) + 1) * 3 + axis(value.x) + 2;
+        Self::from_digit(digit as u8).expect("digit should be in range")
+    }
+
+    /// Returns `true` if the value points in this direction.
+    ///
+    /// `tolerance` extends the 45 degree sector of the direction by the given angle in radians.
+    #[must_use]
+    pub fn matches(self, value: Vec2, dead_zone: f32, tolerance: f32) -> bool {
+        let actuated = value.length_squared() >= dead_zone * dead_zone && value != Vec2::ZERO;
+        if self == Self::Neutral {
+            return !actuated;
+        }
+        if !actuated {
+            return false;
+        }
+
+        let max_angle = FRAC_PI_8 + tolerance;
+        value.normalize().dot(self.as_vec2()) >= ops::cos(max_angle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_enhanced_input_macros::InputAction;
+
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn quantize() {
+        assert_eq!(
+            NumpadDirection::quantize(Vec2::ZERO, 0.5),
+            NumpadDirection::Neutral
+        );
+        assert_eq!(
+            NumpadDirection::quantize(Vec2::new(0.3, 0.0), 0.5),
+            NumpadDirection::Neutral
+        );
+        assert_eq!(
+            NumpadDirection::quantize(Vec2::new(1.0, -1.0), 0.5),
+            NumpadDirection::DownForward
+        );
+        assert_eq!(
+            NumpadDirection::quantize(Vec2::new(-1.0, 0.1), 0.5),
+            NumpadDirection::Back
+        );
+        assert_eq!(
+            NumpadDirection::quantize(Vec2::new(-0.1, 1.0), 0.5),
+            NumpadDirection::Up
+        );
+        for digit in 1..=9 {
+            let direction = NumpadDirection::from_digit(digit).unwrap();
+            assert_eq!(
+                NumpadDirection::quantize(direction.as_vec2(), 0.5),
+                direction
+            );
+        }
+    }
+
+    #[test]
+    fn tolerance() {
+        let value = Vec2::from_angle(FRAC_PI_8 + 0.1);
+        assert!(!NumpadDirection::Forward.matches(value, 0.5, 0.0));
+        assert!(NumpadDirection::Forward.matches(value, 0.5, 0.2));
+        assert!(NumpadDirection::UpForward.matches(value, 0.5, 0.0));
+    }
+
+    #[test]
+    fn quarter_circle() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = MotionInput::quarter_circle_forward();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::ZERO.into()),
+            TriggerState::None
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::NEG_Y.into()),
+            TriggerState::Ongoing
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::NEG_Y.into()),
+            TriggerState::Ongoing
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, (1.0, -1.0).into()),
+            TriggerState::Ongoing
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::X.into()),
+            TriggerState::Fired
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::X.into()),
+            TriggerState::None
+        );
+    }
+
+    #[test]
+    fn mirrored() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = MotionInput::quarter_circle_forward().with_mirrored(true);
+
+        for value in [Vec2::NEG_Y, Vec2::new(-1.0, -1.0)] {
+            assert_eq!(
+                condition.evaluate(&actions, &time, value.into()),
+                TriggerState::Ongoing
+            );
+        }
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::NEG_X.into()),
+            TriggerState::Fired
+        );
+    }
+
+    #[test]
+    fn step_window() {
+        let (mut world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = MotionInput::dragon_punch();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::X.into()),
+            TriggerState::Ongoing
+        );
+
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(150));
+        let (time, actions) = state.get(&world).unwrap();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::X.into()),
+            TriggerState::Ongoing,
+            "holding the previous direction shouldn't consume the window"
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::ZERO.into()),
+            TriggerState::Ongoing
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::ZERO.into()),
+            TriggerState::None
+        );
+        assert_eq!(condition.progress(), 0);
+    }
+
+    #[test]
+    fn charge() {
+        let (mut world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = MotionInput::charge_back_forward(1.0);
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::NEG_X.into()),
+            TriggerState::Ongoing,
+            "should report charging"
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::X.into()),
+            TriggerState::None,
+            "should require charging"
+        );
+
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_secs(1));
+        let (time, actions) = state.get(&world).unwrap();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::NEG_X.into()),
+            TriggerState::Ongoing
+        );
+
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::ZERO);
+        let (time, actions) = state.get(&world).unwrap();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::X.into()),
+            TriggerState::Fired
+        );
+    }
+
+    #[test]
+    fn button() {
+        let (mut world, mut state) = context::init_world();
+        let button = world.spawn(Action::<Test>::new()).id();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = MotionInput::new([NumpadDirection::Down]).with_button(button);
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::NEG_Y.into()),
+            TriggerState::Ongoing
+        );
+
+        *world.get_mut::<ActionEvents>(button).unwrap() = ActionEvents::START;
+        let (time, actions) = state.get(&world).unwrap();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::ZERO.into()),
+            TriggerState::Fired
+        );
+    }
+
+    #[test]
+    fn button_window() {
+        let (mut world, mut state) = context::init_world();
+        let button = world.spawn(Action::<Test>::new()).id();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = MotionInput::new([NumpadDirection::Down])
+            .with_button(button)
+            .with_button_window(0.1);
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::NEG_Y.into()),
+            TriggerState::Ongoing
+        );
+
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(150));
+        let (time, actions) = state.get(&world).unwrap();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::ZERO.into()),
+            TriggerState::None
+        );
+    }
+
+    #[derive(InputAction)]
+    #[action_output(bool)]
+    struct Test;
+}
@@ -0,0 +1,326 @@
+use bevy::prelude::*;
+
+use super::DEFAULT_ACTUATION;
+use crate::prelude::*;
+
+/// Returns [`TriggerState::Fired`] when the input is tapped the defined number of times.
+///
+/// Each tap needs to be released within the defined release time, and the next tap
+/// needs to start within the defined interval after the previous release.
+///
+/// Returns [`TriggerState::Ongoing`] while the sequence is in progress, including the
+/// time between taps. Returns [`TriggerState::None`] if any tap is held longer than the release
+/// time or the interval is exceeded, and halts all triggering until released in the former case.
+///
+/// Unlike [`Combo`], evaluates the input value directly, so it can be attached to a single
+/// binding, such as a direction key for double-tap dashing.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     Player,
+///     actions!(Player[
+///         (
+///             Action::<Dash>::new(),
+///             bindings![
+///                 (KeyCode::KeyA, MultiTap::double(0.3), Negate::all()),
+///                 (KeyCode::KeyD, MultiTap::double(0.3)),
+///             ],
+///         ),
+///     ]),
+/// ));
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(f32)]
+/// struct Dash;
+/// ```
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputCondition)
+)]
+pub struct MultiTap {
+    /// Number of taps required to fire.
+    pub taps: u32,
+
+    /// Trigger threshold.
+    pub actuation: f32,
+
+    /// The type of time used to advance the timers.
+    pub time_kind: TimeKind,
+
+    release_timer: Timer,
+
+    interval_timer: Timer,
+
+    completed_taps: u32,
+
+    actuated: bool,
+}
+
+impl MultiTap {
+    /// Creates a new instance with the given number of taps and maximum interval between them in seconds.
+    ///
+    /// The release time is set to the same value as the interval.
+    /// Use [`Self::with_release_time`] to configure it.
+    #[must_use]
+    pub fn new(taps: u32, interval: f32) -> Self {
+        Self {
+            taps,
+            actuation: DEFAULT_ACTUATION,
+            time_kind: Default::default(),
+            release_timer: Timer::from_seconds(interval, TimerMode::Once),
+            interval_timer: Timer::from_seconds(interval, TimerMode::Once),
+            completed_taps: 0,
+            actuated: false,
+        }
+    }
+
+    /// Creates a new instance that fires on a double tap.
+    ///
+    /// See also [`Self::new`].
+    #[must_use]
+    pub fn double(interval: f32) -> Self {
+        Self::new(2, interval)
+    }
+
+    /// Sets the maximum duration in seconds for which each tap can be held.
+    #[must_use]
+    pub fn with_release_time(mut self, release_time: f32) -> Self {
+        self.release_timer = Timer::from_seconds(release_time, TimerMode::Once);
+        self
+    }
+
+    #[must_use]
+    pub fn with_actuation(mut self, actuation: f32) -> Self {
+        self.actuation = actuation;
+        self
+    }
+
+    #[must_use]
+    pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+
+    /// Returns the timer for the current tap press.
+    #[must_use]
+    pub fn release_timer(&self) -> &Timer {
+        &self.release_timer
+    }
+
+    /// Returns the timer for the interval after the last released tap.
+    #[must_use]
+    pub fn interval_timer(&self) -> &Timer {
+        &self.interval_timer
+    }
+
+    /// Returns the number of taps completed in the current sequence.
+    #[must_use]
+    pub fn completed_taps(&self) -> u32 {
+        self.completed_taps
+    }
+}
+
+impl InputCondition for MultiTap {
+    fn evaluate(
+        &mut self,
+        _actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> TriggerState {
+        let delta = time.delta_kind(self.time_kind);
+        let last_actuated = self.actuated;
+        self.actuated = value.is_actuated(self.actuation);
+
+        if self.actuated {
+            if !last_actuated {
+                self.release_timer.reset();
+                if self.completed_taps > 0 {
+                    self.interval_timer.tick(delta);
+                    if self.interval_timer.is_finished() {
+                        // Pressed too late, start a new sequence.
+                        self.completed_taps = 0;
+                    }
+                }
+            }
+
+            if self.release_timer.is_finished() {
+                // Halt all triggering until released.
+                return TriggerState::None;
+            }
+
+            self.release_timer.tick(delta);
+            if self.release_timer.is_finished() {
+                self.completed_taps = 0;
+                return TriggerState::None;
+            }
+
+            return TriggerState::Ongoing;
+        }
+
+        if last_actuated && !self.release_timer.is_finished() {
+            self.completed_taps += 1;
+            if self.completed_taps >= self.taps {
+                self.completed_taps = 0;
+                return TriggerState::Fired;
+            }
+
+            self.interval_timer.reset();
+            return TriggerState::Ongoing;
+        }
+
+        if self.completed_taps == 0 {
+            return TriggerState::None;
+        }
+
+        self.interval_timer.tick(delta);
+        if self.interval_timer.is_finished() {
+            self.completed_taps = 0;
+            TriggerState::None
+        } else {
+            TriggerState::Ongoing
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn double_tap() {
+        let (mut world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = MultiTap::double(1.0);
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::Ongoing,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::Ongoing,
+        );
+
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(500));
+        let (time, actions) = state.get(&world).unwrap();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::Ongoing,
+        );
+
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::ZERO);
+        let (time, actions) = state.get(&world).unwrap();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::Ongoing,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::Fired,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::None,
+        );
+    }
+
+    #[test]
+    fn interval_exceeded() {
+        let (mut world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = MultiTap::double(1.0);
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::Ongoing,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::Ongoing,
+        );
+
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_secs(1));
+        let (time, actions) = state.get(&world).unwrap();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::None,
+        );
+
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::ZERO);
+        let (time, actions) = state.get(&world).unwrap();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::Ongoing,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::Ongoing,
+            "should start a new sequence"
+        );
+        assert_eq!(condition.completed_taps(), 1);
+    }
+
+    #[test]
+    fn held_too_long() {
+        let (mut world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = MultiTap::new(3, 1.0).with_release_time(0.5);
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::Ongoing,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::Ongoing,
+        );
+
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(500));
+        let (time, actions) = state.get(&world).unwrap();
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::None,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::None,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::None,
+        );
+        assert_eq!(condition.completed_taps(), 0);
+    }
+}
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use super::DEFAULT_ACTUATION;
+use super::{DEFAULT_ACTUATION, hysteresis};
 use crate::prelude::*;
 
 /// Like [`super::press::Down`] but returns [`TriggerState::Fired`] only once until the next actuation.
@@ -13,11 +13,13 @@ use crate::prelude::*;
 #[cfg_attr(
     feature = "reflect",
     derive(Reflect),
-    reflect(Clone, Component, Debug, Default)
+    reflect(Clone, Component, Debug, Default, InputCondition)
 )]
 pub struct Press {
     /// Trigger threshold.
     pub actuation: f32,
+
+    hysteresis: Option<Hysteresis>,
     actuated: bool,
 }
 
@@ -26,9 +28,23 @@ impl Press {
     pub const fn new(actuation: f32) -> Self {
         Self {
             actuation,
+            hysteresis: None,
             actuated: false,
         }
     }
+
+    /// Replaces [`Self::actuation`] with separate press and release thresholds.
+    #[must_use]
+    pub fn with_hysteresis(mut self, hysteresis: Hysteresis) -> Self {
+        self.hysteresis = Some(hysteresis);
+        self
+    }
+
+    /// Returns the thresholds set by [`Self::with_hysteresis`].
+    #[must_use]
+    pub fn hysteresis(&self) -> Option<&Hysteresis> {
+        self.hysteresis.as_ref()
+    }
 }
 
 impl Default for Press {
@@ -41,11 +57,11 @@ impl InputCondition for Press {
     fn evaluate(
         &mut self,
         _actions: &ActionsQuery,
-        _time: &ContextTime,
+        time: &ContextTime,
         value: ActionValue,
     ) -> TriggerState {
         let previously_actuated = self.actuated;
-        self.actuated = value.is_actuated(self.actuation);
+        self.actuated = hysteresis::is_actuated(&mut self.hysteresis, self.actuation, time, value);
 
         if self.actuated && !previously_actuated {
             TriggerState::Fired
@@ -2,7 +2,7 @@ use core::time::Duration;
 
 use bevy::prelude::*;
 
-use super::DEFAULT_ACTUATION;
+use super::{DEFAULT_ACTUATION, hysteresis};
 use crate::prelude::*;
 
 /// Returns [`TriggerState::Ongoing`] when input becomes actuated and [`TriggerState::Fired`]
@@ -11,7 +11,11 @@ use crate::prelude::*;
 /// Note: [`Complete`] only fires when the repeat limit is reached or when input is released
 /// immediately after being triggered. Otherwise, [`Cancel`] is fired when input is released.
 #[derive(Component, Debug, Clone)]
-#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputCondition)
+)]
 pub struct Pulse {
     /// Number of times the condition can be triggered (0 means no limit).
     pub trigger_limit: u32,
@@ -22,6 +26,8 @@ pub struct Pulse {
     /// Trigger threshold.
     pub actuation: f32,
 
+    hysteresis: Option<Hysteresis>,
+
     /// The type of time used to advance the timer.
     pub time_kind: TimeKind,
 
@@ -47,6 +53,7 @@ impl Pulse {
             trigger_limit: 0,
             trigger_on_start: true,
             actuation: DEFAULT_ACTUATION,
+            hysteresis: None,
             time_kind: Default::default(),
             initial_delay: None,
             interval,
@@ -95,6 +102,19 @@ impl Pulse {
         self
     }
 
+    /// Replaces [`Self::actuation`] with separate press and release thresholds.
+    #[must_use]
+    pub fn with_hysteresis(mut self, hysteresis: Hysteresis) -> Self {
+        self.hysteresis = Some(hysteresis);
+        self
+    }
+
+    /// Returns the thresholds set by [`Self::with_hysteresis`].
+    #[must_use]
+    pub fn hysteresis(&self) -> Option<&Hysteresis> {
+        self.hysteresis.as_ref()
+    }
+
     #[must_use]
     pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
         self.time_kind = kind;
@@ -115,7 +135,7 @@ impl InputCondition for Pulse {
         time: &ContextTime,
         value: ActionValue,
     ) -> TriggerState {
-        if value.is_actuated(self.actuation) {
+        if hysteresis::is_actuated(&mut self.hysteresis, self.actuation, time, value) {
             let mut should_fire = false;
 
             if !self.started_actuation {
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use super::DEFAULT_ACTUATION;
+use super::{DEFAULT_ACTUATION, hysteresis};
 use crate::prelude::*;
 
 /// Returns [`TriggerState::Ongoing`] when the input exceeds the actuation threshold and
@@ -12,11 +12,13 @@ use crate::prelude::*;
 #[cfg_attr(
     feature = "reflect",
     derive(Reflect),
-    reflect(Clone, Component, Debug, Default)
+    reflect(Clone, Component, Debug, Default, InputCondition)
 )]
 pub struct Release {
     /// Trigger threshold.
     pub actuation: f32,
+
+    hysteresis: Option<Hysteresis>,
     actuated: bool,
 }
 
@@ -25,9 +27,23 @@ impl Release {
     pub const fn new(actuation: f32) -> Self {
         Self {
             actuation,
+            hysteresis: None,
             actuated: false,
         }
     }
+
+    /// Replaces [`Self::actuation`] with separate press and release thresholds.
+    #[must_use]
+    pub fn with_hysteresis(mut self, hysteresis: Hysteresis) -> Self {
+        self.hysteresis = Some(hysteresis);
+        self
+    }
+
+    /// Returns the thresholds set by [`Self::with_hysteresis`].
+    #[must_use]
+    pub fn hysteresis(&self) -> Option<&Hysteresis> {
+        self.hysteresis.as_ref()
+    }
 }
 
 impl Default for Release {
@@ -40,11 +56,11 @@ impl InputCondition for Release {
     fn evaluate(
         &mut self,
         _actions: &ActionsQuery,
-        _time: &ContextTime,
+        time: &ContextTime,
         value: ActionValue,
     ) -> TriggerState {
         let previously_actuated = self.actuated;
-        self.actuated = value.is_actuated(self.actuation);
+        self.actuated = hysteresis::is_actuated(&mut self.hysteresis, self.actuation, time, value);
 
         if self.actuated {
             // Ongoing on hold.
@@ -0,0 +1,297 @@
+use core::f32::consts::{PI, TAU};
+
+use bevy::prelude::*;
+
+use super::DEFAULT_ACTUATION;
+use crate::prelude::*;
+
+/**
+Returns [`TriggerState::Fired`] when the input points into the angular sector beyond the actuation threshold.
+
+The full circle is split into [`Self::sectors`] equal sectors. Sector `0` is centered on the positive X axis
+and the indices go counter-clockwise, the same way as [`Vec2::to_angle`]. For example, with 8 sectors,
+`0` is right, `2` is up, `4` is left and `6` is down. Only X and Y axes of the value are used.
+
+This is an implicit condition, so it can be combined with explicit conditions. For example,
+with [`Flick`] to fire only when the stick is flicked in a specific direction.
+
+To get the sector index for a value, use [`Self::sector_at`].
+
+# Examples
+
+8-way d-pad emulation from a stick:
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+# let mut world = World::new();
+world.spawn((
+    Player,
+    actions!(Player[
+        (
+            Action::<DodgeLeft>::new(),
+            Flick::new(0.2),
+            Sector::new(4, 8).with_deadband(0.1),
+            Bindings::spawn(Axial::left_stick()),
+        ),
+    ]),
+));
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct DodgeLeft;
+```
+*/
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputCondition)
+)]
+pub struct Sector {
+    /// Index of the sector to fire for.
+    pub index: u32,
+
+    /// Number of sectors the circle is split into.
+    pub sectors: u32,
+
+    /// Magnitude threshold.
+    pub actuation: f32,
+
+    /// Angle in radians added to each side of the sector.
+    ///
+    /// Positive values make neighboring sectors overlap.
+    ///
+    /// By default set to `0.0`.
+    pub tolerance: f32,
+
+    /// Width in radians of the band around the sector borders that prevents switching sectors.
+    ///
+    /// The input needs to go half of the band deeper into the sector to enter it,
+    /// and half of the band past its border to leave it.
+    /// This prevents flickering when the input points close to the border.
+    ///
+    /// By default set to `0.0`.
+    pub deadband: f32,
+
+    active: bool,
+}
+
+impl Sector {
+    /// Creates a new instance for the sector at `index` from `sectors` equal sectors.
+    #[must_use]
+    pub const fn new(index: u32, sectors: u32) -> Self {
+        Self {
+            index,
+            sectors,
+            actuation: DEFAULT_ACTUATION,
+            tolerance: 0.0,
+            deadband: 0.0,
+            active: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_actuation(mut self, actuation: f32) -> Self {
+        self.actuation = actuation;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_deadband(mut self, deadband: f32) -> Self {
+        self.deadband = deadband;
+        self
+    }
+
+    /// Returns the angle in radians of the sector center.
+    #[must_use]
+    pub fn center(&self) -> f32 {
+        center(self.index, self.sectors)
+    }
+
+    /// Returns the index of the sector the value points into.
+    ///
+    /// Uses the same layout as the condition.
+    #[must_use]
+    pub fn sector_at(value: Vec2, sectors: u32) -> u32 {
+        let step = TAU / sectors as f32;
+        let index = ops::round(value.to_angle() / step);
+        ops::rem_euclid(index, sectors as f32) as u32
+    }
+}
+
+impl InputCondition for Sector {
+    fn evaluate(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        value: ActionValue,
+    ) -> TriggerState {
+        let value = value.as_axis2d();
+        if value.length_squared() < self.actuation * self.actuation || self.sectors == 0 {
+            self.active = false;
+            return TriggerState::None;
+        }
+
+        let angle = angle_from_center(value, self.index, self.sectors);
+        let half_width = half_width(self.sectors) + self.tolerance;
+        let limit = if self.active {
+            half_width + self.deadband / 2.0
+        } else {
+            half_width - self.deadband / 2.0
+        };
+
+        self.active = angle.abs() <= limit;
+        if self.active {
+            TriggerState::Fired
+        } else {
+            TriggerState::None
+        }
+    }
+
+    fn kind(&self) -> ConditionKind {
+        ConditionKind::Implicit
+    }
+}
+
+/// Returns the angle in radians of the center of the sector at `index` from `sectors` equal sectors.
+fn center(index: u32, sectors: u32) -> f32 {
+    index as f32 * TAU / sectors as f32
+}
+
+/// Returns half of the angle in radians covered by each of `sectors` equal sectors.
+pub(crate) fn half_width(sectors: u32) -> f32 {
+    PI / sectors as f32
+}
+
+/// Returns the signed angle in radians from the center of the sector at `index` to the value.
+///
+/// The result is in the -PI -> PI range.
+pub(crate) fn angle_from_center(value: Vec2, index: u32, sectors: u32) -> f32 {
+    ops::rem_euclid(value.to_angle() - center(index, sectors) + PI, TAU) - PI
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f32::consts::FRAC_PI_8;
+
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn eight_way() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = Sector::new(2, 8);
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::Y.into()),
+            TriggerState::Fired,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::new(0.3, 1.0).into()),
+            TriggerState::Fired,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::ONE.into()),
+            TriggerState::None,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::NEG_Y.into()),
+            TriggerState::None,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, (Vec2::Y * 0.1).into()),
+            TriggerState::None,
+            "should require the magnitude to exceed the actuation"
+        );
+
+        let mut condition = Sector::new(0, 8);
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::new(1.0, -0.3).into()),
+            TriggerState::Fired,
+            "should wrap around"
+        );
+    }
+
+    #[test]
+    fn tolerance() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let value = Vec2::from_angle(FRAC_PI_8 * 1.5);
+        let mut condition = Sector::new(0, 8);
+        assert_eq!(
+            condition.evaluate(&actions, &time, value.into()),
+            TriggerState::None,
+        );
+
+        let mut condition = Sector::new(0, 8).with_tolerance(FRAC_PI_8);
+        assert_eq!(
+            condition.evaluate(&actions, &time, value.into()),
+            TriggerState::Fired,
+        );
+    }
+
+    #[test]
+    fn deadband() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = Sector::new(0, 8).with_deadband(0.2);
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::from_angle(FRAC_PI_8 - 0.05).into()),
+            TriggerState::None,
+            "should require going deeper to enter"
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::X.into()),
+            TriggerState::Fired,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::from_angle(FRAC_PI_8 + 0.05).into()),
+            TriggerState::Fired,
+            "should allow going past the border to stay"
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::from_angle(FRAC_PI_8 + 0.15).into()),
+            TriggerState::None,
+        );
+    }
+
+    #[test]
+    fn with_flick() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut flick = Flick::new(0.5);
+        let mut sector = Sector::new(4, 8);
+        for (value, expected) in [
+            (Vec2::ZERO, TriggerState::None),
+            (Vec2::NEG_X, TriggerState::Fired),
+        ] {
+            assert_eq!(flick.evaluate(&actions, &time, value.into()), expected);
+            assert_eq!(sector.evaluate(&actions, &time, value.into()), expected);
+        }
+    }
+
+    #[test]
+    fn sector_at() {
+        assert_eq!(Sector::sector_at(Vec2::X, 8), 0);
+        assert_eq!(Sector::sector_at(Vec2::Y, 8), 2);
+        assert_eq!(Sector::sector_at(Vec2::NEG_X, 8), 4);
+        assert_eq!(Sector::sector_at(Vec2::NEG_Y, 8), 6);
+        assert_eq!(Sector::sector_at(Vec2::new(1.0, -0.1), 8), 0);
+        assert_eq!(Sector::sector_at(Vec2::new(-1.0, 0.2), 4), 2);
+    }
+}
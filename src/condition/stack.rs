@@ -0,0 +1,107 @@
+use alloc::vec::Vec;
+
+use bevy::prelude::*;
+
+use crate::{context::trigger_tracker::TriggerTracker, prelude::*};
+
+/**
+Ordered list of conditions of any types evaluated as a single implicit condition.
+
+Allows attaching multiple conditions of the same type to a single entity, such as two [`Chord`]s.
+Conditions inside the stack are combined by their [`ConditionKind`] in the same way as conditions
+on an entity, and the result must be [`TriggerState::Fired`] for the action to fire.
+
+The stack is registered by the plugin. Conditions inside it don't need to be registered,
+but need [`ReflectInputCondition`] to be serialized. See [`ErasedCondition`] for details.
+
+# Examples
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+let mut world = World::new();
+world.spawn((
+    Player,
+    Actions::<Player>::spawn(SpawnWith(|context: &mut ActionSpawner<_>| {
+        let aim = context
+            .spawn((Action::<Aim>::new(), bindings![MouseButton::Right]))
+            .id();
+        let crouch = context
+            .spawn((Action::<Crouch>::new(), bindings![KeyCode::ControlLeft]))
+            .id();
+
+        context.spawn((
+            Action::<SteadyShot>::new(),
+            ConditionStack::default()
+                .with(Chord::single(aim))
+                .with(Chord::single(crouch)),
+            bindings![MouseButton::Left],
+        ));
+    })),
+));
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Aim;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Crouch;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct SteadyShot;
+```
+*/
+#[derive(Component, Debug, Clone, Default, Deref, DerefMut)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, InputCondition)
+)]
+pub struct ConditionStack(pub Vec<ErasedCondition>);
+
+impl ConditionStack {
+    /// Adds a condition to the end of the stack.
+    #[must_use]
+    pub fn with(mut self, condition: impl Into<ErasedCondition>) -> Self {
+        self.0.push(condition.into());
+        self
+    }
+}
+
+impl InputCondition for ConditionStack {
+    fn evaluate(
+        &mut self,
+        actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> TriggerState {
+        let mut tracker = TriggerTracker::new(value);
+        for condition in &mut self.0 {
+            let state = condition.evaluate(actions, time, value);
+            tracker.track_condition(condition.kind(), state);
+        }
+        tracker.state()
+    }
+
+    fn kind(&self) -> ConditionKind {
+        ConditionKind::Implicit
+    }
+
+    fn visit_dependencies(&self, f: &mut dyn FnMut(Entity)) {
+        for condition in &self.0 {
+            condition.visit_dependencies(f);
+        }
+    }
+
+    fn read_world(&mut self, world: &InputWorld) {
+        for condition in &mut self.0 {
+            condition.read_world(world);
+        }
+    }
+}
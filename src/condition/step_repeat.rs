@@ -0,0 +1,307 @@
+use bevy::prelude::*;
+
+use super::DEFAULT_ACTUATION;
+use crate::prelude::*;
+
+/**
+Quantizes the input to cardinal directions and fires steps with an accelerating repeat.
+
+Intended for menu navigation. Acts as both a condition and a modifier. As a modifier, replaces the value
+with the unit vector of the nearest cardinal direction as [`ActionValue::Axis2D`], or zero if the input
+is below [`Self::actuation`]. As a condition, returns [`TriggerState::Fired`] once when a direction
+is pressed, then again after [`Self::initial_delay`], and then repeatedly with an interval that shrinks
+by [`Self::acceleration`] down to [`Self::min_interval`]. Returns [`TriggerState::Ongoing`] between steps.
+
+Changing the direction starts over with an immediate step.
+
+Works with any number of digital and analog bindings, see [`Navigation`] for a preset.
+Use [`IVec2`] as the action output and read the step from [`Fire`] events.
+
+[`ActionValue::Bool`] and [`ActionValue::Axis1D`] are treated as the X axis.
+
+# Examples
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+# let mut world = World::new();
+world.spawn((
+    Menu,
+    actions!(Menu[
+        (
+            Action::<Navigate>::new(),
+            StepRepeat::default(),
+            Bindings::spawn(Navigation::new()),
+        ),
+    ]),
+));
+
+fn navigate(fire: On<Fire<Navigate>>) {
+    info!("moving selection by {}", fire.value);
+}
+
+#[derive(Component)]
+struct Menu;
+
+#[derive(InputAction)]
+#[action_output(IVec2)]
+struct Navigate;
+```
+*/
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, InputCondition, InputModifier)
+)]
+pub struct StepRepeat {
+    /// Time in seconds between the first step and the first repeat.
+    ///
+    /// By default set to 0.4.
+    pub initial_delay: f32,
+
+    /// Time in seconds between the first and the second repeat.
+    ///
+    /// By default set to 0.15.
+    pub interval: f32,
+
+    /// Multiplier applied to the interval after each repeat.
+    ///
+    /// Values below 1.0 make repeats faster the longer the input is held.
+    ///
+    /// By default set to 0.85.
+    pub acceleration: f32,
+
+    /// Minimum time in seconds between repeats.
+    ///
+    /// By default set to 0.05.
+    pub min_interval: f32,
+
+    /// Magnitude of the input required to step.
+    pub actuation: f32,
+
+    /// The type of time used to advance the repeat.
+    pub time_kind: TimeKind,
+
+    /// Current direction with components from -1 to 1.
+    direction: IVec2,
+
+    /// Time in seconds since the last step.
+    elapsed: f32,
+
+    /// Time in seconds until the next step.
+    next_step: f32,
+
+    /// Whether the first repeat already happened.
+    repeating: bool,
+
+    /// State calculated during the last transformation.
+    state: TriggerState,
+}
+
+impl StepRepeat {
+    /// Creates a new instance with the given delay before the first repeat and the initial repeat interval in seconds.
+    #[must_use]
+    pub fn new(initial_delay: f32, interval: f32) -> Self {
+        Self {
+            initial_delay,
+            interval,
+            acceleration: 0.85,
+            min_interval: 0.05,
+            actuation: DEFAULT_ACTUATION,
+            time_kind: Default::default(),
+            direction: IVec2::ZERO,
+            elapsed: 0.0,
+            next_step: 0.0,
+            repeating: false,
+            state: TriggerState::None,
+        }
+    }
+
+    /// Sets [`Self::acceleration`] and [`Self::min_interval`].
+    #[must_use]
+    pub fn with_acceleration(mut self, acceleration: f32, min_interval: f32) -> Self {
+        self.acceleration = acceleration;
+        self.min_interval = min_interval;
+        self
+    }
+
+    #[must_use]
+    pub fn with_actuation(mut self, actuation: f32) -> Self {
+        self.actuation = actuation;
+        self
+    }
+
+    #[must_use]
+    pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+
+    /// Returns the current direction.
+    #[must_use]
+    pub fn direction(&self) -> IVec2 {
+        self.direction
+    }
+
+    fn quantize(&self, value: Vec2) -> IVec2 {
+        if value.length() < self.actuation {
+            return IVec2::ZERO;
+        }
+
+        let abs = value.abs();
+        if abs.x >= abs.y {
+            IVec2::new(value.x.signum() as i32, 0)
+        } else {
+            IVec2::new(0, value.y.signum() as i32)
+        }
+    }
+}
+
+impl Default for StepRepeat {
+    fn default() -> Self {
+        Self::new(0.4, 0.15)
+    }
+}
+
+impl InputModifier for StepRepeat {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        let direction = self.quantize(value.as_axis2d());
+        if direction == IVec2::ZERO {
+            self.reset();
+        } else if direction != self.direction {
+            self.direction = direction;
+            self.elapsed = 0.0;
+            self.next_step = self.initial_delay;
+            self.repeating = false;
+            self.state = TriggerState::Fired;
+        } else {
+            self.elapsed += time.delta_kind(self.time_kind).as_secs_f32();
+            if self.elapsed >= self.next_step {
+                self.elapsed -= self.next_step;
+                self.next_step = if self.repeating {
+                    (self.next_step * self.acceleration).max(self.min_interval)
+                } else {
+                    self.interval
+                };
+                self.repeating = true;
+                self.state = TriggerState::Fired;
+            } else {
+                self.state = TriggerState::Ongoing;
+            }
+        }
+
+        direction.into()
+    }
+
+    fn reset(&mut self) {
+        self.direction = IVec2::ZERO;
+        self.elapsed = 0.0;
+        self.next_step = 0.0;
+        self.repeating = false;
+        self.state = TriggerState::None;
+    }
+}
+
+impl InputCondition for StepRepeat {
+    fn evaluate(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        _value: ActionValue,
+    ) -> TriggerState {
+        self.state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use core::time::Duration;
+
+    use super::*;
+    use crate::{context, prelude::TriggerState::*};
+
+    #[test]
+    fn quantize() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = StepRepeat::default();
+        assert_eq!(
+            condition.transform(&actions, &time, Vec2::new(0.7, 0.6).into()),
+            Vec2::X.into()
+        );
+        assert_eq!(
+            condition.transform(&actions, &time, Vec2::new(-0.2, -0.9).into()),
+            Vec2::NEG_Y.into()
+        );
+        assert_eq!(
+            condition.transform(&actions, &time, true.into()),
+            Vec2::X.into()
+        );
+        assert_eq!(
+            condition.transform(&actions, &time, Vec2::new(0.3, 0.3).into()),
+            Vec2::ZERO.into()
+        );
+    }
+
+    #[test]
+    fn repeat() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(100));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = StepRepeat::new(0.3, 0.2).with_acceleration(0.5, 0.1);
+        let mut states = Vec::new();
+        for _ in 0..10 {
+            condition.transform(&actions, &time, Vec2::Y.into());
+            states.push(condition.evaluate(&actions, &time, Vec2::Y.into()));
+        }
+
+        assert_eq!(
+            states,
+            [
+                Fired, Ongoing, Ongoing, Fired, Ongoing, Fired, Fired, Fired, Fired, Fired
+            ]
+        );
+    }
+
+    #[test]
+    fn direction_change() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(100));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut condition = StepRepeat::default();
+        condition.transform(&actions, &time, Vec2::X.into());
+        condition.transform(&actions, &time, Vec2::X.into());
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::X.into()),
+            TriggerState::Ongoing
+        );
+
+        condition.transform(&actions, &time, Vec2::Y.into());
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::Y.into()),
+            TriggerState::Fired
+        );
+        assert_eq!(condition.direction(), IVec2::Y);
+
+        condition.transform(&actions, &time, Vec2::ZERO.into());
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::ZERO.into()),
+            TriggerState::None
+        );
+    }
+}
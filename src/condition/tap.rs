@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use super::DEFAULT_ACTUATION;
+use super::{DEFAULT_ACTUATION, hysteresis};
 use crate::prelude::*;
 
 /// Returns [`TriggerState::Ongoing`] when input becomes actuated and [`TriggerState::Fired`]
@@ -8,11 +8,17 @@ use crate::prelude::*;
 ///
 /// Returns [`TriggerState::None`] when the input is actuated more than the defined release time.
 #[derive(Component, Debug, Clone)]
-#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputCondition)
+)]
 pub struct Tap {
     /// Trigger threshold.
     pub actuation: f32,
 
+    hysteresis: Option<Hysteresis>,
+
     /// The type of time used to advance the timer.
     pub time_kind: TimeKind,
 
@@ -27,6 +33,7 @@ impl Tap {
     pub fn new(release_time: f32) -> Self {
         Self {
             actuation: DEFAULT_ACTUATION,
+            hysteresis: None,
             time_kind: Default::default(),
             timer: Timer::from_seconds(release_time, TimerMode::Once),
             actuated: false,
@@ -39,6 +46,19 @@ impl Tap {
         self
     }
 
+    /// Replaces [`Self::actuation`] with separate press and release thresholds.
+    #[must_use]
+    pub fn with_hysteresis(mut self, hysteresis: Hysteresis) -> Self {
+        self.hysteresis = Some(hysteresis);
+        self
+    }
+
+    /// Returns the thresholds set by [`Self::with_hysteresis`].
+    #[must_use]
+    pub fn hysteresis(&self) -> Option<&Hysteresis> {
+        self.hysteresis.as_ref()
+    }
+
     #[must_use]
     pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
         self.time_kind = kind;
@@ -61,7 +81,7 @@ impl InputCondition for Tap {
     ) -> TriggerState {
         let last_actuated = self.actuated;
         let finished = self.timer.is_finished();
-        self.actuated = value.is_actuated(self.actuation);
+        self.actuated = hysteresis::is_actuated(&mut self.hysteresis, self.actuation, time, value);
         if self.actuated {
             self.timer.tick(time.delta_kind(self.time_kind));
         } else {
@@ -16,7 +16,7 @@ use crate::prelude::*;
 #[cfg_attr(
     feature = "reflect",
     derive(Reflect),
-    reflect(Clone, Component, Debug, Default)
+    reflect(Clone, Component, Debug, Default, InputCondition)
 )]
 pub struct Toggle {
     /// Trigger threshold.
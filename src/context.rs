@@ -5,7 +5,8 @@ They allow you to define when actions are active and which inputs they respond t
 Actions are checked only if their context is active,
 and are evaluated in the order of their context's [`ContextPriority`],
 then mainly by the order in which the actions were added to the context,
-with the first action having the highest priority.
+with the first action having the highest priority. Actions referenced by conditions
+are evaluated first, see [referenced actions](crate::condition#referenced-actions).
 
 Further details on how to order actions due to their inputs being consumed
 can be found in the documentation for [`ActionSettings::consume_input`].
@@ -57,11 +58,13 @@ When an action is despawned, it automatically transitions its state to [`Trigger
 triggering the corresponding events. Depending on your use case, using [`ContextActivity`] might be more convenient than removal.
 */
 
+pub(crate) mod dependency;
 pub mod input_reader;
+pub mod input_world;
 pub mod instance;
 #[allow(deprecated)]
 pub mod time;
-mod trigger_tracker;
+pub(crate) mod trigger_tracker;
 
 #[cfg(feature = "reflect")]
 use core::any::type_name;
@@ -79,9 +82,10 @@ use bevy::{
     ecs::{
         component::ComponentId,
         entity_disabling::Disabled,
+        resource::IsResource,
         schedule::ScheduleLabel,
-        system::{ParamBuilder, QueryParamBuilder},
-        world::{FilteredEntityMut, FilteredEntityRef},
+        system::{FilteredResourcesParamBuilder, ParamBuilder, QueryParamBuilder},
+        world::{FilteredEntityMut, FilteredEntityRef, FilteredResources},
     },
     prelude::*,
 };
@@ -90,10 +94,15 @@ use log::{debug, trace};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    action::fns::ActionFns,
+    action::{events::ReportedValue, fns::ActionFns},
     binding::FirstActivation,
     condition::fns::{ConditionFns, ConditionRegistry},
-    context::{input_reader::PendingBindings, trigger_tracker::TriggerTracker},
+    context::{
+        dependency::{DependencySorter, EvaluatedActions},
+        input_reader::PendingBindings,
+        input_world::InputWorldRegistry,
+        trigger_tracker::TriggerTracker,
+    },
     modifier::fns::{ModifierFns, ModifierRegistry},
     prelude::*,
 };
@@ -121,6 +130,8 @@ pub trait InputContextAppExt {
     ///     6. Convert the final value to [`ActionOutput::DIM`] again using [`ActionValue::convert`].
     ///     7. Apply the resulting [`TriggerState`] and [`ActionValue`] to the action entity.
     ///     8. If the final state is not [`TriggerState::None`], consume the binding input value.
+    /// - If the state changed to [`TriggerState::None`], call [`InputModifier::reset`] for all binding-level
+    ///   and action-level modifiers.
     ///
     /// This logic may look complicated, but you don't have to memorize it. It behaves surprisingly intuitively.
     fn add_input_context<C: Component>(&mut self) -> &mut Self {
@@ -208,7 +219,7 @@ pub(crate) struct ScheduleContexts {
     activity_ids: Vec<ComponentId>,
 
     /// Configures the app for this schedule.
-    setup: fn(&Self, &mut App, &ConditionRegistry, &ModifierRegistry),
+    setup: fn(&Self, &mut App, &ConditionRegistry, &ModifierRegistry, &InputWorldRegistry),
 }
 
 impl ScheduleContexts {
@@ -233,8 +244,9 @@ impl ScheduleContexts {
         app: &mut App,
         conditions: &ConditionRegistry,
         modifiers: &ModifierRegistry,
+        world: &InputWorldRegistry,
     ) {
-        (self.setup)(self, app, conditions, modifiers);
+        (self.setup)(self, app, conditions, modifiers, world);
     }
 
     /// Configures the app for all contexts registered for schedule `C`.
@@ -243,6 +255,7 @@ impl ScheduleContexts {
         app: &mut App,
         conditions: &ConditionRegistry,
         modifiers: &ModifierRegistry,
+        world: &InputWorldRegistry,
     ) {
         debug!("setting up systems for `{}`", ShortName::of::<S>());
 
@@ -251,6 +264,27 @@ impl ScheduleContexts {
             ParamBuilder,
             ParamBuilder,
             ParamBuilder,
+            FilteredResourcesParamBuilder::new(|builder| {
+                for &id in &world.resources {
+                    builder.add_read_by_id(id);
+                }
+            }),
+            QueryParamBuilder::new(|builder| {
+                builder.optional(|builder| {
+                    for &id in &world.components {
+                        assert!(
+                            !conditions.contains(&id)
+                                && !modifiers.contains(&id)
+                                && !self.activity_ids.contains(&id)
+                                && !self.actions_ids.contains(&id),
+                            "components mutated during evaluation can't be registered for `InputWorld` access"
+                        );
+                        builder.ref_id(id);
+                    }
+                });
+            }),
+            ParamBuilder,
+            ParamBuilder,
             QueryParamBuilder::new(|builder| {
                 builder
                     .data::<Option<&GamepadDevice>>()
@@ -276,6 +310,8 @@ impl ScheduleContexts {
                     }
                 });
             }),
+            ParamBuilder,
+            ParamBuilder,
         )
             .build_state(app.world_mut())
             .build_system(update::<S>);
@@ -294,6 +330,8 @@ impl ScheduleContexts {
                 });
             }),
             ParamBuilder,
+            ParamBuilder,
+            ParamBuilder,
         )
             .build_state(app.world_mut())
             .build_system(apply::<S>);
@@ -371,6 +409,7 @@ pub(crate) fn reset_action<C: Component>(
     remove: On<Remove, ActionOf<C>>,
     mut commands: Commands,
     mut pending: ResMut<PendingBindings>,
+    mut messages: Option<ResMut<Messages<ActionEventMessage>>>,
     mut actions: Query<(
         &ActionOf<C>,
         &ActionSettings,
@@ -380,20 +419,36 @@ pub(crate) fn reset_action<C: Component>(
         &mut TriggerState,
         &mut ActionEvents,
         &mut ActionTime,
+        &mut ReportedValue,
     )>,
     bindings: Query<&Binding>,
 ) {
-    let Ok((action_of, settings, fns, action_bindings, mut value, mut state, mut events, mut time)) =
-        actions.get_mut(remove.entity)
+    let Ok((
+        action_of,
+        settings,
+        fns,
+        action_bindings,
+        mut value,
+        mut state,
+        mut events,
+        mut time,
+        mut reported_value,
+    )) = actions.get_mut(remove.entity)
     else {
         trace!("ignoring reset for `{}`", remove.entity);
         return;
     };
 
     *time = Default::default();
-    events.set_if_neq(ActionEvents::new(*state, TriggerState::None));
+    let zero = ActionValue::zero(value.dim());
+    events.set_if_neq(ActionEvents::with_value_change(
+        *state,
+        (TriggerState::None, zero),
+        &mut reported_value,
+        settings.value_change_epsilon,
+    ));
     state.set_if_neq(Default::default());
-    value.set_if_neq(ActionValue::zero(value.dim()));
+    value.set_if_neq(zero);
 
     fns.trigger(
         &mut commands,
@@ -405,6 +460,19 @@ pub(crate) fn reset_action<C: Component>(
         *time,
     );
 
+    if let Some(messages) = &mut messages
+        && !events.is_empty()
+    {
+        messages.write(ActionEventMessage {
+            context: **action_of,
+            action: remove.entity,
+            events: *events,
+            value: *value,
+            state: *state,
+            time: *time,
+        });
+    }
+
     if let Some(action_bindings) = action_bindings
         && settings.require_reset
     {
@@ -423,7 +491,11 @@ pub struct ExternallyMocked;
 #[allow(clippy::too_many_arguments)]
 fn update<S: ScheduleLabel>(
     mut consume_buffer: Local<Vec<Binding>>, // Consumed inputs during state evaluation.
+    mut sorter: Local<DependencySorter>,
+    mut evaluated: ResMut<EvaluatedActions>,
     time: ContextTime,
+    world_resources: FilteredResources,
+    world_entities: Query<FilteredEntityRef<'static, 'static>>,
     mut reader: InputReader,
     instances: Res<ContextInstances<S>>,
     mut contexts: Query<FilteredEntityMut>,
@@ -436,6 +508,7 @@ fn update<S: ScheduleLabel>(
             Option<&ModifierFns>,
             Option<&ConditionFns>,
             &mut ActionMock,
+            &mut ReportedValue,
         ),
         Without<ExternallyMocked>,
     >,
@@ -456,10 +529,17 @@ fn update<S: ScheduleLabel>(
         Without<ActionSettings>,
     >,
     mut conds_and_mods: Query<FilteredEntityMut>,
+    changed: Query<(), Or<(Changed<Bindings>, Changed<Binding>, Changed<ConditionFns>)>>,
+    mut removed_bindings: RemovedComponents<Bindings>,
 ) {
     reader.clear_consumed::<S>();
+    let input_world = InputWorld::new(world_resources, world_entities);
 
-    for instance in &**instances {
+    // Actions are stored in the sorted order, so they need to be sorted again
+    // only if the actions, their bindings, conditions or referenced actions have changed.
+    let order_changed = !changed.is_empty() || removed_bindings.read().count() > 0;
+
+    for (index, instance) in instances.iter().enumerate() {
         let Ok(mut context) = contexts.get_mut(instance.entity()) else {
             trace!(
                 "skipping updating `{}` on disabled `{}`",
@@ -475,21 +555,55 @@ fn update<S: ScheduleLabel>(
             continue;
         };
 
-        let mods_count = |action: &Entity| {
-            let Ok((.., action_bindings, _, _, _)) = actions.get(*action) else {
-                return Reverse(0);
+        let mut collect_dependencies = |action, dependencies: &mut Vec<Entity>| {
+            let Ok((.., action_bindings, _, conditions, _, _)) = actions.get(action) else {
+                return;
             };
 
-            let value = bindings
-                .iter_many(action_bindings.into_iter().flatten())
-                .map(|(_, b, ..)| b.mod_keys_count())
-                .max()
-                .unwrap_or(0);
-            Reverse(value)
+            let mut visit = |entity, conditions: &ConditionFns| {
+                let mut entity = conds_and_mods.get_mut(entity).unwrap();
+                for get_condition in &**conditions {
+                    get_condition(&mut entity)
+                        .visit_dependencies(&mut |action| dependencies.push(action));
+                }
+            };
+
+            if let Some(conditions) = conditions {
+                visit(action, conditions);
+            }
+            for (binding, .., conditions) in
+                bindings.iter_many(action_bindings.into_iter().flatten())
+            {
+                if let Some(conditions) = conditions {
+                    visit(binding, conditions);
+                }
+            }
         };
 
-        if !context_actions.is_sorted_by_key(mods_count) {
-            context_actions.sort_by_cached_key(mods_count);
+        let dependencies_changed =
+            sorter.dependencies_changed(index, &context_actions, &mut collect_dependencies);
+        if order_changed || dependencies_changed || context_actions.is_changed() {
+            let mods_count = |action: &Entity| {
+                let Ok((.., action_bindings, _, _, _, _)) = actions.get(*action) else {
+                    return Reverse(0);
+                };
+
+                let value = bindings
+                    .iter_many(action_bindings.into_iter().flatten())
+                    .map(|(_, b, ..)| b.mod_keys_count())
+                    .max()
+                    .unwrap_or(0);
+                Reverse(value)
+            };
+
+            let sorted = sorter.sort(&context_actions, mods_count, collect_dependencies);
+            if *sorted != *context_actions {
+                context_actions.copy_from_slice(sorted);
+            }
+            evaluated.set_actions(instance.key(), &context_actions, |action| {
+                actions_data.contains(action)
+            });
+            sorter.check_evaluated(&evaluated, |action| actions_data.contains(action));
         }
 
         trace!("updating `{}` on `{}`", instance.name(), instance.entity());
@@ -505,6 +619,7 @@ fn update<S: ScheduleLabel>(
             modifiers,
             conditions,
             mut mock,
+            mut reported_value,
         )) = actions_iter.fetch_next()
         {
             let action_name = ShortName(action_name);
@@ -571,6 +686,7 @@ fn update<S: ScheduleLabel>(
                             &mut binding_entity,
                             &actions_data,
                             &time,
+                            &input_world,
                             modifiers,
                         );
                     }
@@ -579,6 +695,7 @@ fn update<S: ScheduleLabel>(
                             &mut binding_entity,
                             &actions_data,
                             &time,
+                            &input_world,
                             conditions,
                         );
                     }
@@ -616,10 +733,22 @@ fn update<S: ScheduleLabel>(
                 trace!("applying `{action_name}` modifiers and conditions");
                 let mut action = conds_and_mods.get_mut(action).unwrap();
                 if let Some(modifiers) = modifiers {
-                    tracker.apply_modifiers(&mut action, &actions_data, &time, modifiers);
+                    tracker.apply_modifiers(
+                        &mut action,
+                        &actions_data,
+                        &time,
+                        &input_world,
+                        modifiers,
+                    );
                 }
                 if let Some(conditions) = conditions {
-                    tracker.apply_conditions(&mut action, &actions_data, &time, conditions);
+                    tracker.apply_conditions(
+                        &mut action,
+                        &actions_data,
+                        &time,
+                        &input_world,
+                        conditions,
+                    );
                 }
 
                 let new_state = tracker.state();
@@ -642,11 +771,32 @@ fn update<S: ScheduleLabel>(
             let (mut value, mut state, mut events, mut action_time) =
                 actions_data.get_mut(action).unwrap();
 
+            if *state != TriggerState::None && new_state == TriggerState::None {
+                trace!("resetting `{action_name}` modifiers");
+                let mut bindings_iter =
+                    bindings.iter_many_mut(action_bindings.into_iter().flatten());
+                while let Some((binding, .., modifiers, _)) = bindings_iter.fetch_next() {
+                    if let Some(modifiers) = modifiers {
+                        modifiers.reset(&mut conds_and_mods.get_mut(binding).unwrap());
+                    }
+                }
+                if let Some(modifiers) = modifiers {
+                    modifiers.reset(&mut conds_and_mods.get_mut(action).unwrap());
+                }
+            }
+
             action_time.update(time.delta_secs(), *state);
-            events.set_if_neq(ActionEvents::new(*state, new_state));
+            events.set_if_neq(ActionEvents::with_value_change(
+                *state,
+                (new_state, new_value),
+                &mut reported_value,
+                action_settings.value_change_epsilon,
+            ));
             state.set_if_neq(new_state);
             value.set_if_neq(new_value);
         }
+
+        evaluated.insert(instance.key());
     }
 }
 
@@ -665,7 +815,11 @@ fn apply<S: ScheduleLabel>(
     mut commands: Commands,
     instances: Res<ContextInstances<S>>,
     contexts: Query<FilteredEntityRef, Without<ActionFns>>,
-    mut actions: Query<EntityMut, (With<ActionFns>, Without<ContextInstances<S>>)>,
+    // Resources are stored on entities, so exclude them to avoid conflicts with `Res` parameters,
+    // such as the time resources read by `ContextTime` to timestamp `ActionHistory`.
+    mut actions: Query<EntityMut, (With<ActionFns>, Without<IsResource>)>,
+    mut messages: Option<ResMut<Messages<ActionEventMessage>>>,
+    time: ContextTime,
 ) {
     for instance in &**instances {
         let Ok(context) = contexts.get(instance.entity()) else {
@@ -693,6 +847,33 @@ fn apply<S: ScheduleLabel>(
             fns.store_value(&mut action, value);
 
             let state = *action.get::<TriggerState>().unwrap();
+            if let Some(mut history) = action.get_mut::<ActionHistory>() {
+                let timestamp = time.elapsed_kind(history.time_kind);
+                history.push(value, state, timestamp);
+            }
+
+            let action_id = action.id();
+            if let Some(mut menu) = action.get_mut::<RadialMenu>() {
+                // Update a copy to avoid triggering change detection if nothing changed.
+                let mut updated = menu.clone();
+                let selected = updated.update(state, value);
+                if updated.hovered() != menu.hovered() {
+                    commands.trigger(RadialHover {
+                        context: context.id(),
+                        action: action_id,
+                        index: updated.hovered(),
+                    });
+                }
+                menu.set_if_neq(updated);
+                if let Some(index) = selected {
+                    commands.trigger(RadialSelect {
+                        context: context.id(),
+                        action: action_id,
+                        index,
+                    });
+                }
+            }
+
             let events = *action.get::<ActionEvents>().unwrap();
             let time = *action.get::<ActionTime>().unwrap();
             fns.trigger(
@@ -704,6 +885,19 @@ fn apply<S: ScheduleLabel>(
                 value,
                 time,
             );
+
+            if let Some(messages) = &mut messages
+                && !events.is_empty()
+            {
+                messages.write(ActionEventMessage {
+                    context: context.id(),
+                    action: action.id(),
+                    events,
+                    value,
+                    state,
+                    time,
+                });
+            }
         }
     }
 }
@@ -0,0 +1,351 @@
+use core::{any::TypeId, hash::BuildHasher, ops::Range};
+
+use bevy::{
+    ecs::entity::EntityHashMap,
+    platform::{collections::HashSet, hash::FixedHasher},
+    prelude::*,
+};
+use log::warn;
+
+/// Contexts evaluated during the current frame across all schedules.
+///
+/// Used to detect references to actions that weren't evaluated yet.
+#[derive(Resource, Default)]
+pub(crate) struct EvaluatedActions {
+    /// Context of each action, updated when the context's actions are sorted.
+    contexts: EntityHashMap<ContextKey>,
+
+    /// Contexts evaluated during the current frame.
+    evaluated: HashSet<ContextKey>,
+}
+
+impl EvaluatedActions {
+    /// Replaces the actions associated with the context.
+    ///
+    /// Also removes actions for which `is_action` returns `false`.
+    pub(super) fn set_actions(
+        &mut self,
+        context: ContextKey,
+        actions: &[Entity],
+        is_action: impl Fn(Entity) -> bool,
+    ) {
+        self.contexts
+            .retain(|&action, &mut key| key != context && is_action(action));
+        self.contexts
+            .extend(actions.iter().map(|&action| (action, context)));
+    }
+
+    /// Marks the context as evaluated during the current frame.
+    pub(super) fn insert(&mut self, context: ContextKey) {
+        self.evaluated.insert(context);
+    }
+
+    /// Returns `true` if the context of the action was evaluated during the current frame.
+    pub(super) fn contains(&self, action: Entity) -> bool {
+        self.contexts
+            .get(&action)
+            .is_some_and(|context| self.evaluated.contains(context))
+    }
+}
+
+/// Context entity and the type of its context component.
+pub(super) type ContextKey = (Entity, TypeId);
+
+pub(crate) fn clear_evaluated(mut evaluated: ResMut<EvaluatedActions>) {
+    evaluated.evaluated.clear();
+}
+
+/// Reorders actions within a context so that actions referenced by conditions are evaluated first.
+///
+/// The dependency order is secondary to the key: dependencies are moved only
+/// within a group of actions with the same key.
+#[derive(Default)]
+pub(super) struct DependencySorter {
+    /// Actions sorted by the key.
+    actions: Vec<Entity>,
+
+    /// Index of each action in [`Self::actions`].
+    positions: EntityHashMap<usize>,
+
+    /// Group of actions with the same key for each action from [`Self::actions`].
+    groups: Vec<usize>,
+
+    /// Dependencies of all actions, indexed by [`Self::ranges`].
+    dependencies: Vec<Entity>,
+
+    /// Range in [`Self::dependencies`] for each action from [`Self::actions`].
+    ranges: Vec<Range<usize>>,
+
+    marks: Vec<Mark>,
+
+    /// Actions in dependency order.
+    sorted: Vec<Entity>,
+
+    /// Pairs of actions and their dependencies that were already reported since the last sort.
+    warned: HashSet<(Entity, Entity)>,
+
+    /// Hash of dependencies for each context, indexed by the context position.
+    hashes: Vec<u64>,
+
+    /// Buffer for dependencies of a single action.
+    collected: Vec<Entity>,
+}
+
+impl DependencySorter {
+    /// Returns `true` if dependencies of `actions` differ from the previous call with the same `index`.
+    ///
+    /// Used to detect conditions that were mutated to reference different actions.
+    /// `collect` should push all actions referenced by the action's conditions.
+    pub(super) fn dependencies_changed(
+        &mut self,
+        index: usize,
+        actions: &[Entity],
+        mut collect: impl FnMut(Entity, &mut Vec<Entity>),
+    ) -> bool {
+        // Combine hashes of each action with addition to make the result independent of the actions order.
+        let mut hash = 0u64;
+        for &action in actions {
+            self.collected.clear();
+            collect(action, &mut self.collected);
+            hash = hash.wrapping_add(FixedHasher.hash_one((action, &self.collected)));
+        }
+
+        if self.hashes.len() <= index {
+            self.hashes.resize(index + 1, 0);
+        }
+        let changed = self.hashes[index] != hash;
+        self.hashes[index] = hash;
+        changed
+    }
+
+    /// Sorts actions by `key`, then moves dependencies before actions with the same key that reference them.
+    ///
+    /// `collect` should push all actions referenced by the action's conditions.
+    ///
+    /// Returns actions in the sorted order.
+    pub(super) fn sort<K: Ord>(
+        &mut self,
+        actions: &[Entity],
+        mut key: impl FnMut(&Entity) -> K,
+        mut collect: impl FnMut(Entity, &mut Vec<Entity>),
+    ) -> &[Entity] {
+        self.actions.clear();
+        self.positions.clear();
+        self.groups.clear();
+        self.dependencies.clear();
+        self.ranges.clear();
+        self.marks.clear();
+        self.sorted.clear();
+        self.warned.clear();
+
+        let mut keys: Vec<_> = actions
+            .iter()
+            .map(|action| (key(action), *action))
+            .collect();
+        keys.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let mut group = 0;
+        for (index, (action_key, action)) in keys.iter().enumerate() {
+            if index > 0 && *action_key != keys[index - 1].0 {
+                group += 1;
+            }
+            self.actions.push(*action);
+            self.positions.insert(*action, index);
+            self.groups.push(group);
+        }
+
+        for &action in &self.actions {
+            let start = self.dependencies.len();
+            collect(action, &mut self.dependencies);
+            self.ranges.push(start..self.dependencies.len());
+            self.marks.push(Mark::New);
+        }
+
+        for index in 0..self.actions.len() {
+            self.visit(index);
+        }
+
+        &self.sorted
+    }
+
+    /// Warns about dependencies that are not evaluated before their actions.
+    ///
+    /// Should be called after [`Self::sort`].
+    pub(super) fn check_evaluated(
+        &mut self,
+        evaluated: &EvaluatedActions,
+        is_action: impl Fn(Entity) -> bool,
+    ) {
+        for (&action, range) in self.actions.iter().zip(&self.ranges) {
+            for &dependency in &self.dependencies[range.clone()] {
+                if self.positions.contains_key(&dependency)
+                    || evaluated.contains(dependency)
+                    || !is_action(dependency)
+                {
+                    continue;
+                }
+
+                if self.warned.insert((action, dependency)) {
+                    warn!(
+                        "`{action}` references `{dependency}` that is evaluated later, \
+                        its state from the previous evaluation will be used; \
+                        make sure its context has a higher priority or runs in an earlier schedule"
+                    );
+                }
+            }
+        }
+    }
+
+    fn visit(&mut self, index: usize) {
+        if self.marks[index] != Mark::New {
+            return;
+        }
+
+        self.marks[index] = Mark::Visiting;
+        let action = self.actions[index];
+        for dependency_index in self.ranges[index].clone() {
+            let dependency = self.dependencies[dependency_index];
+            let Some(&position) = self.positions.get(&dependency) else {
+                continue;
+            };
+
+            if self.groups[position] != self.groups[index] {
+                if position > index && self.warned.insert((action, dependency)) {
+                    warn!(
+                        "`{action}` references `{dependency}` that is evaluated later \
+                        because its bindings have fewer modifier keys, \
+                        its state from the previous evaluation will be used"
+                    );
+                }
+                continue;
+            }
+
+            if self.marks[position] == Mark::Visiting {
+                if self.warned.insert((action, dependency)) {
+                    warn!(
+                        "`{action}` and `{dependency}` reference each other, \
+                        `{dependency}` will use the state from the previous evaluation"
+                    );
+                }
+                continue;
+            }
+
+            self.visit(position);
+        }
+
+        self.marks[index] = Mark::Visited;
+        self.sorted.push(action);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mark {
+    New,
+    Visiting,
+    Visited,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn independent() {
+        let mut world = World::new();
+        let actions = [world.spawn_empty().id(), world.spawn_empty().id()];
+
+        let mut sorter = DependencySorter::default();
+        let sorted = sorter.sort(&actions, |_| 0, |_, _| ());
+        assert_eq!(sorted, actions);
+    }
+
+    #[test]
+    fn dependencies_first() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let c = world.spawn_empty().id();
+
+        let mut sorter = DependencySorter::default();
+        let sorted = sorter.sort(
+            &[a, b, c],
+            |_| 0,
+            |action, dependencies| {
+                if action == a {
+                    dependencies.push(c);
+                } else if action == c {
+                    dependencies.push(b);
+                }
+            },
+        );
+        assert_eq!(sorted, [b, c, a]);
+    }
+
+    #[test]
+    fn cycle() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+
+        let mut sorter = DependencySorter::default();
+        let sorted = sorter.sort(
+            &[a, b],
+            |_| 0,
+            |action, dependencies| {
+                dependencies.push(if action == a { b } else { a });
+            },
+        );
+        assert_eq!(sorted, [b, a]);
+        assert!(sorter.warned.contains(&(b, a)));
+
+        sorter.sort(&[a], |_| 0, |_, _| ());
+        assert!(
+            sorter.warned.is_empty(),
+            "warnings should be reset after sorting"
+        );
+    }
+
+    #[test]
+    fn changed_dependencies() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+
+        let mut sorter = DependencySorter::default();
+        assert!(sorter.dependencies_changed(0, &[a, b], |_, _| ()));
+        assert!(!sorter.dependencies_changed(0, &[a, b], |_, _| ()));
+        assert!(
+            !sorter.dependencies_changed(0, &[b, a], |_, _| ()),
+            "order of actions shouldn't matter"
+        );
+        assert!(
+            sorter.dependencies_changed(0, &[a, b], |action, dependencies| {
+                if action == a {
+                    dependencies.push(b);
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn key_first() {
+        let mut world = World::new();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        let c = world.spawn_empty().id();
+
+        let mut sorter = DependencySorter::default();
+        let sorted = sorter.sort(
+            &[a, b, c],
+            |&action| if action == c { 0 } else { 1 },
+            |action, dependencies| {
+                if action == c {
+                    dependencies.push(b);
+                } else if action == b {
+                    dependencies.push(a);
+                }
+            },
+        );
+        assert_eq!(sorted, [c, a, b], "dependencies shouldn't override the key");
+        assert!(sorter.warned.contains(&(c, b)));
+    }
+}
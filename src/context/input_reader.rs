@@ -1,4 +1,5 @@
 pub mod custom;
+pub mod motion_sensor;
 
 use alloc::vec::Vec;
 use core::{any::TypeId, hash::Hash, iter, mem};
@@ -0,0 +1,523 @@
+//! Adapter that turns gyro samples into [`Binding::Custom`] values.
+
+use alloc::vec::Vec;
+
+use bevy::{
+    ecs::{lifecycle::HookContext, world::DeferredWorld},
+    prelude::*,
+};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use super::InputReader;
+use crate::prelude::*;
+
+/**
+Converts angular velocity samples from a gyro into [`CustomInputs`] values.
+
+Bevy doesn't expose motion sensors, so samples need to be pushed from your own source,
+such as a platform SDK, with [`Self::push_sample`]. Samples are consumed during
+[`EnhancedInputSystems::Prepare`] and integrated into rotation in radians since the last update,
+similar to mouse motion. Bind the result with [`Self::axis_2d`] or [`Self::axis_3d`].
+
+The output is corrected by [`Self::offset`] to remove the sensor bias. It can be measured with
+[`Self::start_calibration`] and [`Self::finish_calibration`] while the device rests on a surface,
+or corrected continuously with [`Self::auto_calibration`].
+
+When the component is removed, its values are removed from [`CustomInputs`],
+so the bindings read as [`ActionValue::Bool`] `false`.
+
+To temporarily disable the output, like lifting a mouse off the pad, set [`Self::ratchet`]
+or drive it from an input with [`Self::with_ratchet`]. The ratchet binding is read from the gamepad
+assigned with [`GamepadDevice`] on the sensor entity, or from any gamepad if it's missing.
+
+# Examples
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+# let mut app = App::new();
+# app.add_plugins((MinimalPlugins, EnhancedInputPlugin));
+let mut custom_inputs = app.world_mut().resource_mut::<CustomInputs>();
+let sensor = MotionSensor::new(&mut custom_inputs)
+    .with_space(GyroSpace::Player)
+    .with_ratchet(GamepadButton::LeftTrigger);
+let gyro = sensor.axis_2d();
+
+app.world_mut().spawn(sensor);
+app.world_mut().spawn((
+    Player,
+    actions!(Player[(Action::<Aim>::new(), Scale::splat(2.0), bindings![gyro])]),
+));
+
+app.add_systems(
+    PreUpdate,
+    read_gyro.before(EnhancedInputSystems::Prepare),
+);
+
+fn read_gyro(mut sensors: Query<&mut MotionSensor>) {
+    for mut sensor in &mut sensors {
+        // Read from your platform SDK here.
+        sensor.push_sample(MotionSample::new(Vec3::ZERO, Vec3::Y, 0.004));
+    }
+}
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Aim;
+```
+*/
+#[derive(Component, Debug, Clone)]
+#[component(on_remove = on_remove)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+pub struct MotionSensor {
+    /// Space in which [`Self::axis_2d`] is calculated.
+    ///
+    /// By default set to [`GyroSpace::Local`].
+    pub space: GyroSpace,
+
+    /// Sensor bias in radians per second that is subtracted from each sample.
+    ///
+    /// Updated by [`Self::finish_calibration`] and [`Self::auto_calibration`].
+    ///
+    /// By default set to [`Vec3::ZERO`].
+    pub offset: Vec3,
+
+    /// Continuous drift correction while the sensor is still.
+    ///
+    /// By default set to [`None`].
+    pub auto_calibration: Option<AutoCalibration>,
+
+    /// Whether the output is disabled.
+    ///
+    /// Samples are still used for calibration, but the output is zero.
+    ///
+    /// By default set to `false`.
+    pub ratchet: bool,
+
+    /// Input that sets [`Self::ratchet`] while actuated.
+    ///
+    /// Overrides [`Self::ratchet`] on every update if set.
+    ///
+    /// By default set to [`None`].
+    pub ratchet_binding: Option<Binding>,
+
+    axis_2d: CustomInput,
+    axis_3d: CustomInput,
+    samples: Vec<MotionSample>,
+    calibration: CalibrationState,
+    gravity: Vec3,
+    last_sample: Option<MotionSample>,
+    still_secs: f32,
+}
+
+impl MotionSensor {
+    /// Creates a new instance and registers its inputs in [`CustomInputs`].
+    #[must_use]
+    pub fn new(custom_inputs: &mut CustomInputs) -> Self {
+        Self {
+            space: Default::default(),
+            offset: Vec3::ZERO,
+            auto_calibration: None,
+            ratchet: false,
+            ratchet_binding: None,
+            axis_2d: custom_inputs.register_input(),
+            axis_3d: custom_inputs.register_input(),
+            samples: Default::default(),
+            calibration: Default::default(),
+            gravity: Vec3::ZERO,
+            last_sample: None,
+            still_secs: 0.0,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_space(mut self, space: GyroSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_auto_calibration(mut self, auto_calibration: AutoCalibration) -> Self {
+        self.auto_calibration = Some(auto_calibration);
+        self
+    }
+
+    /// Sets [`Self::ratchet_binding`].
+    #[must_use]
+    pub fn with_ratchet(mut self, binding: impl Into<Binding>) -> Self {
+        self.ratchet_binding = Some(binding.into());
+        self
+    }
+
+    /// Returns a binding for yaw and pitch in radians as [`ActionValue::Axis2D`].
+    ///
+    /// X is positive when turning right and Y is positive when tilting up.
+    /// Converted according to [`Self::space`].
+    #[must_use]
+    pub const fn axis_2d(&self) -> Binding {
+        Binding::Custom(self.axis_2d)
+    }
+
+    /// Returns a binding for rotation around each sensor axis in radians as [`ActionValue::Axis3D`].
+    ///
+    /// Always in [`GyroSpace::Local`].
+    #[must_use]
+    pub const fn axis_3d(&self) -> Binding {
+        Binding::Custom(self.axis_3d)
+    }
+
+    /// Queues a sample to be processed on the next update.
+    pub fn push_sample(&mut self, sample: MotionSample) {
+        self.samples.push(sample);
+    }
+
+    /// Starts averaging samples to measure [`Self::offset`].
+    ///
+    /// The device should rest still until [`Self::finish_calibration`] is called.
+    /// The output is zero during calibration.
+    pub fn start_calibration(&mut self) {
+        self.calibration = CalibrationState::Calibrating {
+            sum: Vec3::ZERO,
+            count: 0,
+        };
+    }
+
+    /// Finishes the calibration started with [`Self::start_calibration`].
+    ///
+    /// Assigns the average of the received samples to [`Self::offset`].
+    /// Keeps the previous offset if no samples were received.
+    pub fn finish_calibration(&mut self) {
+        if let CalibrationState::Calibrating { sum, count } = self.calibration
+            && count > 0
+        {
+            self.offset = sum / count as f32;
+        }
+        self.calibration = CalibrationState::Idle;
+    }
+
+    /// Returns `true` if the calibration is in progress.
+    #[must_use]
+    pub const fn is_calibrating(&self) -> bool {
+        matches!(self.calibration, CalibrationState::Calibrating { .. })
+    }
+
+    /// Returns the smoothed acceleration, which points up when the sensor is at rest.
+    ///
+    /// Used for [`GyroSpace::Player`] and [`GyroSpace::World`].
+    #[must_use]
+    pub const fn gravity(&self) -> Vec3 {
+        self.gravity
+    }
+
+    /// Processes a single sample and returns the corrected rotation in radians.
+    fn process(&mut self, sample: MotionSample) -> Vec3 {
+        if sample.acceleration != Vec3::ZERO {
+            if self.gravity == Vec3::ZERO {
+                self.gravity = sample.acceleration;
+            } else {
+                let factor = 1.0 - ops::exp(-GRAVITY_SMOOTHING * sample.delta_secs);
+                self.gravity = self.gravity.lerp(sample.acceleration, factor);
+            }
+        }
+
+        if let Some(auto_calibration) = self.auto_calibration {
+            let still = self.last_sample.is_some_and(|last| {
+                last.angular_velocity.distance(sample.angular_velocity)
+                    <= auto_calibration.angular_threshold
+                    && last.acceleration.distance(sample.acceleration)
+                        <= auto_calibration.acceleration_threshold
+            });
+            if still {
+                self.still_secs += sample.delta_secs;
+            } else {
+                self.still_secs = 0.0;
+            }
+
+            if self.still_secs >= auto_calibration.still_secs {
+                let factor = 1.0 - ops::exp(-auto_calibration.correction_rate * sample.delta_secs);
+                self.offset = self.offset.lerp(sample.angular_velocity, factor);
+            }
+        }
+        self.last_sample = Some(sample);
+
+        if let CalibrationState::Calibrating { sum, count } = &mut self.calibration {
+            *sum += sample.angular_velocity;
+            *count += 1;
+            return Vec3::ZERO;
+        }
+
+        (sample.angular_velocity - self.offset) * sample.delta_secs
+    }
+
+    /// Converts rotation around sensor axes into yaw and pitch.
+    fn to_yaw_pitch(&self, rotation: Vec3) -> Vec2 {
+        let up = self.gravity.normalize_or_zero();
+        if up == Vec3::ZERO {
+            return Vec2::new(-rotation.y, rotation.x);
+        }
+
+        match self.space {
+            GyroSpace::Local => Vec2::new(-rotation.y, rotation.x),
+            GyroSpace::Player => {
+                let world_yaw = rotation.y * up.y + rotation.z * up.z;
+                let yaw = (world_yaw.abs() * PLAYER_SPACE_SLACK)
+                    .min(Vec2::new(rotation.y, rotation.z).length())
+                    .copysign(world_yaw);
+                Vec2::new(-yaw, rotation.x)
+            }
+            GyroSpace::World => {
+                let right = (Vec3::X - up * up.x).normalize_or_zero();
+                Vec2::new(-rotation.dot(up), rotation.dot(right))
+            }
+        }
+    }
+}
+
+/// Rate in 1/s at which [`MotionSensor::gravity`] follows the acceleration.
+const GRAVITY_SMOOTHING: f32 = 4.0;
+
+/// Allows yaw in [`GyroSpace::Player`] to exceed its projection on the up axis,
+/// so turning stays responsive when the controller is tilted.
+const PLAYER_SPACE_SLACK: f32 = 1.41;
+
+/// A single reading of a motion sensor for [`MotionSensor::push_sample`].
+///
+/// Axes follow the controller: X points right, Y points up and Z points towards the player.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Default, PartialEq)
+)]
+pub struct MotionSample {
+    /// Angular velocity in radians per second, counter-clockwise around each axis.
+    pub angular_velocity: Vec3,
+
+    /// Acceleration including gravity in any units.
+    ///
+    /// Only the direction and the changes are used. Can be zero if the accelerometer is unavailable,
+    /// in which case [`GyroSpace::Player`] and [`GyroSpace::World`] behave like [`GyroSpace::Local`].
+    pub acceleration: Vec3,
+
+    /// Time in seconds since the previous sample.
+    pub delta_secs: f32,
+}
+
+impl MotionSample {
+    #[must_use]
+    pub const fn new(angular_velocity: Vec3, acceleration: Vec3, delta_secs: f32) -> Self {
+        Self {
+            angular_velocity,
+            acceleration,
+            delta_secs,
+        }
+    }
+}
+
+/// Space in which [`MotionSensor::axis_2d`] is calculated.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Default, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum GyroSpace {
+    /// Yaw and pitch around the controller axes.
+    ///
+    /// Works the same regardless of how the controller is held, but turning
+    /// depends on the controller tilt.
+    #[default]
+    Local,
+    /// Yaw around the up axis mixed with the roll, pitch around the controller axis.
+    ///
+    /// Tolerates different grips, so works well for most players.
+    Player,
+    /// Yaw around the gravity axis, pitch around the horizontal axis.
+    ///
+    /// Matches the physical rotation, but requires an accurate gravity direction.
+    World,
+}
+
+/// Continuous drift correction for [`MotionSensor::auto_calibration`].
+///
+/// When samples stay stable for [`Self::still_secs`], the sensor is considered
+/// at rest and [`MotionSensor::offset`] is gradually moved towards the measured angular velocity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Default, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct AutoCalibration {
+    /// Maximum change of the angular velocity in radians per second between samples
+    /// to consider the sensor still.
+    ///
+    /// By default set to 0.05.
+    pub angular_threshold: f32,
+
+    /// Maximum change of the acceleration between samples to consider the sensor still.
+    ///
+    /// Uses the same units as [`MotionSample::acceleration`].
+    ///
+    /// By default set to 0.05.
+    pub acceleration_threshold: f32,
+
+    /// Time in seconds the sensor needs to stay still before the correction starts.
+    ///
+    /// By default set to 1.0.
+    pub still_secs: f32,
+
+    /// Rate in 1/s at which the offset moves towards the measured angular velocity.
+    ///
+    /// By default set to 2.0.
+    pub correction_rate: f32,
+}
+
+impl Default for AutoCalibration {
+    fn default() -> Self {
+        Self {
+            angular_threshold: 0.05,
+            acceleration_threshold: 0.05,
+            still_secs: 1.0,
+            correction_rate: 2.0,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Debug, Default))]
+enum CalibrationState {
+    #[default]
+    Idle,
+    Calibrating {
+        sum: Vec3,
+        count: u32,
+    },
+}
+
+pub(crate) fn update_sensors(
+    // Reader also accesses custom inputs, so they can't be borrowed at the same time.
+    mut params: ParamSet<(InputReader, ResMut<CustomInputs>)>,
+    mut sensors: Query<(&mut MotionSensor, Option<&GamepadDevice>)>,
+) {
+    for (mut sensor, gamepad) in &mut sensors {
+        if let Some(binding) = sensor.ratchet_binding {
+            let mut reader = params.p0();
+            reader.set_gamepad(gamepad.copied().unwrap_or_default());
+            sensor.ratchet = reader.value(binding).as_bool();
+        }
+
+        let mut samples = core::mem::take(&mut sensor.samples);
+        let rotation: Vec3 = samples.drain(..).map(|sample| sensor.process(sample)).sum();
+        sensor.samples = samples; // Reuse the allocation.
+
+        let (rotation, yaw_pitch) = if sensor.ratchet {
+            (Vec3::ZERO, Vec2::ZERO)
+        } else {
+            (rotation, sensor.to_yaw_pitch(rotation))
+        };
+        let mut custom_inputs = params.p1();
+        custom_inputs.insert(sensor.axis_2d, yaw_pitch.into());
+        custom_inputs.insert(sensor.axis_3d, rotation.into());
+    }
+}
+
+fn on_remove(mut world: DeferredWorld, ctx: HookContext) {
+    let sensor = world.get::<MotionSensor>(ctx.entity).unwrap();
+    let inputs = [sensor.axis_2d, sensor.axis_3d];
+    if let Some(mut custom_inputs) = world.get_resource_mut::<CustomInputs>() {
+        for input in inputs {
+            custom_inputs.remove(&input);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local() {
+        let mut sensor = MotionSensor::new(&mut CustomInputs::default());
+        let rotation = sensor.process(MotionSample::new(Vec3::new(1.0, 2.0, 0.0), Vec3::Y, 0.5));
+        assert_eq!(rotation, Vec3::new(0.5, 1.0, 0.0));
+        assert_eq!(sensor.to_yaw_pitch(rotation), Vec2::new(-1.0, 0.5));
+    }
+
+    #[test]
+    fn player() {
+        let mut sensor =
+            MotionSensor::new(&mut CustomInputs::default()).with_space(GyroSpace::Player);
+        sensor.process(MotionSample::new(Vec3::ZERO, Vec3::Z, 1.0));
+
+        // Upright controller, turning around the screen normal.
+        let yaw_pitch = sensor.to_yaw_pitch(Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(yaw_pitch, Vec2::new(-1.0, 0.0));
+    }
+
+    #[test]
+    fn world() {
+        let mut sensor =
+            MotionSensor::new(&mut CustomInputs::default()).with_space(GyroSpace::World);
+        sensor.process(MotionSample::new(Vec3::ZERO, Vec3::Z, 1.0));
+
+        let yaw_pitch = sensor.to_yaw_pitch(Vec3::new(0.5, 1.0, 1.0));
+        assert_eq!(yaw_pitch, Vec2::new(-1.0, 0.5));
+    }
+
+    #[test]
+    fn calibration() {
+        let mut sensor = MotionSensor::new(&mut CustomInputs::default());
+        sensor.start_calibration();
+        assert!(sensor.is_calibrating());
+
+        let bias = Vec3::new(0.1, -0.2, 0.05);
+        for _ in 0..4 {
+            let rotation = sensor.process(MotionSample::new(bias, Vec3::Y, 0.1));
+            assert_eq!(rotation, Vec3::ZERO);
+        }
+
+        sensor.finish_calibration();
+        assert!(!sensor.is_calibrating());
+        assert!(sensor.offset.abs_diff_eq(bias, 1e-6));
+
+        let rotation = sensor.process(MotionSample::new(bias, Vec3::Y, 0.1));
+        assert!(rotation.abs_diff_eq(Vec3::ZERO, 1e-6));
+    }
+
+    #[test]
+    fn auto_calibration() {
+        let mut sensor = MotionSensor::new(&mut CustomInputs::default())
+            .with_auto_calibration(AutoCalibration::default());
+
+        let drift = Vec3::new(0.0, 0.03, 0.0);
+        for _ in 0..500 {
+            sensor.process(MotionSample::new(drift, Vec3::Y, 0.01));
+        }
+        assert!(sensor.offset.abs_diff_eq(drift, 1e-4));
+
+        sensor.process(MotionSample::new(Vec3::Y, Vec3::Y, 0.01));
+        assert_eq!(sensor.still_secs, 0.0, "motion should reset stillness");
+    }
+}
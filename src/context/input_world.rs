@@ -0,0 +1,124 @@
+use bevy::{
+    ecs::{
+        component::ComponentId,
+        world::{FilteredEntityRef, FilteredResources},
+    },
+    prelude::*,
+};
+use log::debug;
+
+use crate::{condition::fns::ConditionRegistry, modifier::fns::ModifierRegistry, prelude::*};
+
+/// An extension trait for [`App`] to make data readable by conditions and modifiers.
+pub trait InputWorldAppExt {
+    /// Allows conditions and modifiers to read the resource during evaluation via [`InputWorld`].
+    ///
+    /// Should be called before the app finishes building, like registering contexts.
+    /// Systems that evaluate contexts will read this resource, so it can't be mutated in parallel with them.
+    fn add_input_resource<R: Resource>(&mut self) -> &mut Self;
+
+    /// Allows conditions and modifiers to read the component during evaluation via [`InputWorld`].
+    ///
+    /// Should be called before the app finishes building, like registering contexts.
+    /// Systems that evaluate contexts will read this component on all entities,
+    /// so it can't be mutated in parallel with them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the component is mutated during evaluation, such as [`ActionValue`],
+    /// [`TriggerState`] or a registered condition or modifier.
+    fn add_input_component<C: Component>(&mut self) -> &mut Self;
+}
+
+impl InputWorldAppExt for App {
+    fn add_input_resource<R: Resource>(&mut self) -> &mut Self {
+        debug!("registering `{}` for input world", ShortName::of::<R>());
+        let id = self.world_mut().register_component::<R>();
+        let mut registry = self.world_mut().resource_mut::<InputWorldRegistry>();
+        if !registry.resources.contains(&id) {
+            registry.resources.push(id);
+        }
+        self
+    }
+
+    fn add_input_component<C: Component>(&mut self) -> &mut Self {
+        debug!("registering `{}` for input world", ShortName::of::<C>());
+        let id = self.world_mut().register_component::<C>();
+        assert!(
+            !is_mutated(self.world(), id),
+            "`{}` is mutated during evaluation and can't be registered for `InputWorld` access",
+            ShortName::of::<C>()
+        );
+        let mut registry = self.world_mut().resource_mut::<InputWorldRegistry>();
+        if !registry.components.contains(&id) {
+            registry.components.push(id);
+        }
+        self
+    }
+}
+
+/// Returns `true` if the component is mutated by the systems that evaluate contexts.
+///
+/// Conditions and modifiers registered later are checked when the systems are built.
+fn is_mutated(world: &World, id: ComponentId) -> bool {
+    let components = world.components();
+    let action_data = [
+        components.component_id::<ActionValue>(),
+        components.component_id::<TriggerState>(),
+        components.component_id::<ActionEvents>(),
+        components.component_id::<ActionTime>(),
+        components.component_id::<ActionMock>(),
+    ];
+
+    action_data.contains(&Some(id))
+        || world.resource::<ConditionRegistry>().contains(&id)
+        || world.resource::<ModifierRegistry>().contains(&id)
+}
+
+/// IDs of resources and components accessible via [`InputWorld`].
+///
+/// Used to dynamically register access for [`FilteredResources`] and [`FilteredEntityRef`].
+///
+/// Exists only during the plugin initialization.
+#[derive(Resource, Default)]
+pub(crate) struct InputWorldRegistry {
+    pub(super) resources: Vec<ComponentId>,
+    pub(super) components: Vec<ComponentId>,
+}
+
+/// Read-only access to resources and components during the evaluation of conditions and modifiers.
+///
+/// Passed to [`InputCondition::read_world`]
+/// and [`InputModifier::read_world`].
+///
+/// Only resources and components registered with [`InputWorldAppExt`] are accessible.
+/// This keeps the access of the evaluation systems known in advance, so they can still run
+/// in parallel with systems that don't mutate the registered data.
+pub struct InputWorld<'w, 's> {
+    resources: FilteredResources<'w, 's>,
+    entities: Query<'w, 's, FilteredEntityRef<'static, 'static>>,
+}
+
+impl<'w, 's> InputWorld<'w, 's> {
+    pub(super) fn new(
+        resources: FilteredResources<'w, 's>,
+        entities: Query<'w, 's, FilteredEntityRef<'static, 'static>>,
+    ) -> Self {
+        Self {
+            resources,
+            entities,
+        }
+    }
+
+    /// Returns the resource if it exists and was registered with [`InputWorldAppExt::add_input_resource`].
+    #[must_use]
+    pub fn resource<R: Resource>(&self) -> Option<&R> {
+        self.resources.get::<R>().ok().map(Ref::into_inner)
+    }
+
+    /// Returns the component of the entity if it exists and was registered with [`InputWorldAppExt::add_input_component`].
+    #[must_use]
+    pub fn get<C: Component>(&self, entity: Entity) -> Option<&C> {
+        self.entities.get(entity).ok()?.get::<C>()
+    }
+}
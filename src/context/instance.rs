@@ -8,7 +8,10 @@ use bevy::{
     prelude::*,
 };
 
-use crate::{context::ContextActivity, prelude::*};
+use crate::{
+    context::{ContextActivity, dependency::ContextKey},
+    prelude::*,
+};
 
 /// Stores information about instantiated contexts for a schedule `S`.
 ///
@@ -110,6 +113,11 @@ impl ContextInstance {
         self.entity
     }
 
+    /// Returns the context entity and the type of its context component.
+    pub(super) fn key(&self) -> ContextKey {
+        (self.entity, self.type_id)
+    }
+
     /// Returns the context type name.
     pub fn name(&self) -> ShortName<'_> {
         self.name
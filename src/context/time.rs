@@ -25,6 +25,15 @@ impl ContextTime<'_> {
             TimeKind::Real => self.real.delta(),
         }
     }
+
+    /// Returns the elapsed time of the time resource corresponding to the given [`TimeKind`].
+    #[must_use]
+    pub fn elapsed_kind(&self, kind: TimeKind) -> Duration {
+        match kind {
+            TimeKind::Auto | TimeKind::Virtual => self.auto.elapsed(),
+            TimeKind::Real => self.real.elapsed(),
+        }
+    }
 }
 
 /// Type of the [`Time`] resource to use.
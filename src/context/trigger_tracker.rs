@@ -6,7 +6,7 @@ use crate::{condition::fns::ConditionFns, modifier::fns::ModifierFns, prelude::*
 /// Helper for computing [`TriggerState`] and [`ActionValue`] based on modifiers and conditions.
 ///
 /// Can be used at both the input level and the action level.
-pub(super) struct TriggerTracker {
+pub(crate) struct TriggerTracker {
     value: ActionValue,
     found_explicit: bool,
     any_explicit_fired: bool,
@@ -18,7 +18,7 @@ pub(super) struct TriggerTracker {
 
 impl TriggerTracker {
     #[must_use]
-    pub(super) fn new(value: ActionValue) -> Self {
+    pub(crate) fn new(value: ActionValue) -> Self {
         Self {
             value,
             found_explicit: false,
@@ -35,10 +35,12 @@ impl TriggerTracker {
         entity: &mut FilteredEntityMut,
         actions: &ActionsQuery,
         time: &ContextTime,
+        world: &InputWorld,
         fns: &ModifierFns,
     ) {
         for get_modifier in &**fns {
             let modifier = get_modifier(entity);
+            modifier.read_world(world);
             let new_value = modifier.transform(actions, time, self.value);
             trace!(
                 "`{modifier:?}` changes `{:?}` to `{new_value:?}`",
@@ -54,33 +56,40 @@ impl TriggerTracker {
         entity: &mut FilteredEntityMut,
         actions: &ActionsQuery,
         time: &ContextTime,
+        world: &InputWorld,
         conditions: &ConditionFns,
     ) {
         // Note: No early outs permitted!
         // All conditions must be evaluated to update their internal state/delta time.
         for get_condition in &**conditions {
             let condition = get_condition(entity);
+            condition.read_world(world);
             let state = condition.evaluate(actions, time, self.value);
             trace!("`{condition:?}` returns state `{state:?}`");
-            match condition.kind() {
-                ConditionKind::Explicit => {
-                    self.found_explicit = true;
-                    self.any_explicit_fired |= state == TriggerState::Fired;
-                    self.found_active |= state != TriggerState::None;
-                }
-                ConditionKind::Implicit => {
-                    self.found_implicit = true;
-                    self.all_implicits_fired &= state == TriggerState::Fired;
-                    self.found_active |= state != TriggerState::None;
-                }
-                ConditionKind::Blocker => {
-                    self.blocked |= state == TriggerState::None;
-                }
+            self.track_condition(condition.kind(), state);
+        }
+    }
+
+    /// Combines the state of a single condition with previously tracked conditions.
+    pub(crate) fn track_condition(&mut self, kind: ConditionKind, state: TriggerState) {
+        match kind {
+            ConditionKind::Explicit => {
+                self.found_explicit = true;
+                self.any_explicit_fired |= state == TriggerState::Fired;
+                self.found_active |= state != TriggerState::None;
+            }
+            ConditionKind::Implicit => {
+                self.found_implicit = true;
+                self.all_implicits_fired &= state == TriggerState::Fired;
+                self.found_active |= state != TriggerState::None;
+            }
+            ConditionKind::Blocker => {
+                self.blocked |= state == TriggerState::None;
             }
         }
     }
 
-    pub(super) fn state(&self) -> TriggerState {
+    pub(crate) fn state(&self) -> TriggerState {
         if self.blocked {
             return TriggerState::None;
         }
@@ -220,6 +220,9 @@ The event system is highly flexible. For example, you can use the [`Hold`] condi
 
 This approach can be mixed with the pull-style API if you need to access values of other actions in your observer.
 
+If you need to process events in a particular system order or in a different schedule,
+you can opt-in into the buffered [`ActionEventMessage`] and read it with [`MessageReader`].
+
 ### Pull-style: polling action state
 
 Sometimes you may want to access multiple actions at the same time, or check an action state
@@ -232,9 +235,14 @@ To access the action state, use the [`TriggerState`] component. State transition
 in the [`ActionEvents`] component, which lets you detect when an action has just started or stopped triggering.
 
 Timing information provided via [`ActionTime`] component.
+If you need values and states from previous evaluations, insert [`ActionHistory`].
+For radial selection from a 2D action, like weapon wheels, insert [`RadialMenu`].
 
 You can also use Bevy's change detection - these components marked as changed only if their values actually change.
 
+To run a system only when an action is triggered, use one of the [run conditions](crate::common_conditions),
+such as [`action_started`].
+
 For single-player games you can use [`Single`] for convenient access:
 
 ```
@@ -345,12 +353,15 @@ extern crate self as bevy_enhanced_input;
 
 pub mod action;
 pub mod binding;
+pub mod common_conditions;
 pub mod condition;
 pub mod context;
 pub mod modifier;
 pub mod preset;
 #[cfg(feature = "state")]
 pub mod state;
+#[cfg(all(feature = "reflect", feature = "serialize"))]
+mod type_registry;
 
 pub mod prelude {
     #[cfg(feature = "state")]
@@ -361,7 +372,9 @@ pub mod prelude {
             Accumulation, Action, ActionOutput, ActionSettings, ActionTime, InputAction,
             TriggerState,
             events::*,
+            history::{ActionHistory, HistoryEntry},
             mock::{ActionMock, MockEntityCommandsExt, MockEntityWorldMutExt, MockSpan},
+            radial_menu::{RadialHover, RadialInput, RadialMenu, RadialSelect},
             relationship::{ActionOf, ActionSpawner, ActionSpawnerCommands, Actions},
             value::{ActionValue, ActionValueDim},
         },
@@ -374,24 +387,34 @@ pub mod prelude {
             },
         },
         bindings,
+        common_conditions::*,
         condition::{
-            ConditionKind, InputCondition, block_by::*, chord::*, combo::*, cooldown::*, down::*,
-            flick::*, fns::InputConditionAppExt, hold::*, hold_and_release::*, press::*, pulse::*,
-            release::*, tap::*, toggle::*,
+            ConditionKind, InputCondition, block_by::*, charge::*, chord::*, combo::*, cooldown::*,
+            down::*, erased::*, flick::*, fns::InputConditionAppExt, hold::*, hold_and_release::*,
+            hysteresis::*, logic::*, motion_input::*, multi_tap::*, press::*, pulse::*, release::*,
+            sector::*, stack::*, step_repeat::*, tap::*, toggle::*,
         },
         context::{
             ActionsQuery, ContextActivity, ContextPriority, GamepadDevice, InputContextAppExt,
             input_reader::{
                 ActionSources,
                 custom::{CustomInput, CustomInputs},
+                motion_sensor::{AutoCalibration, GyroSpace, MotionSample, MotionSensor},
             },
+            input_world::{InputWorld, InputWorldAppExt},
         },
         modifier::{
-            InputModifier, accumulate_by::*, clamp::*, dead_zone::*, delta_scale::*,
-            exponential_curve::*, fns::InputModifierAppExt, linear_step::*, negate::*, scale::*,
-            smooth_nudge::*, swizzle_axis::*,
+            InputModifier, accumulate_by::*, aim_assist::*, clamp::*, dead_zone::*, delta_scale::*,
+            derivative::*, erased::*, exponential_curve::*, exponential_moving_average::*,
+            fns::InputModifierAppExt, input_settings::*, integrate::*, linear_step::*,
+            mouse_acceleration::*, moving_average::*, negate::*, one_euro_filter::*,
+            response_curve::*, rotate_by_entity::*, scale::*, smooth_nudge::*, snap_direction::*,
+            stack::*, swizzle_axis::*,
+        },
+        preset::{
+            WithBundle, axial::*, bidirectional::*, cardinal::*, navigation::*, ordinal::*,
+            spatial::*,
         },
-        preset::{WithBundle, axial::*, bidirectional::*, cardinal::*, ordinal::*, spatial::*},
     };
     #[allow(deprecated)]
     pub use super::{
@@ -406,7 +429,9 @@ use bevy::{input::InputSystems, prelude::*};
 use condition::fns::ConditionRegistry;
 use context::{
     ContextRegistry,
-    input_reader::{self, ConsumedInputs, PendingBindings},
+    dependency::{self, EvaluatedActions},
+    input_reader::{self, ConsumedInputs, PendingBindings, motion_sensor},
+    input_world::InputWorldRegistry,
 };
 use modifier::fns::ModifierRegistry;
 use prelude::{Press, Release, *};
@@ -421,32 +446,63 @@ impl Plugin for EnhancedInputPlugin {
         app.init_resource::<ContextRegistry>()
             .init_resource::<ConsumedInputs>()
             .init_resource::<PendingBindings>()
+            .init_resource::<EvaluatedActions>()
             .init_resource::<ActionSources>()
             .init_resource::<CustomInputs>()
             .init_resource::<ConditionRegistry>()
             .init_resource::<ModifierRegistry>()
+            .init_resource::<InputWorldRegistry>()
+            .init_resource::<AimTargets>()
+            .init_resource::<InputSettings>()
+            .add_input_condition::<AllConditions>()
+            .add_input_condition::<AnyConditions>()
             .add_input_condition::<BlockBy>()
+            .add_input_condition::<Charge>()
             .add_input_condition::<Chord>()
             .add_input_condition::<Combo>()
+            .add_input_condition::<ConditionStack>()
             .add_input_condition::<Down>()
             .add_input_condition::<Flick>()
             .add_input_condition::<Hold>()
             .add_input_condition::<HoldAndRelease>()
+            .add_input_condition::<Hysteresis>()
+            .add_input_condition::<MotionInput>()
+            .add_input_condition::<MultiTap>()
+            .add_input_condition::<NotCondition>()
             .add_input_condition::<Press>()
             .add_input_condition::<Pulse>()
             .add_input_condition::<Release>()
+            .add_input_condition::<Sector>()
+            .add_input_condition::<StepRepeat>()
             .add_input_condition::<Tap>()
             .add_input_condition::<Cooldown>()
             .add_input_condition::<Toggle>()
             .add_input_modifier::<AccumulateBy>()
+            .add_input_modifier::<AimMagnetism>()
+            .add_input_modifier::<AimSlowdown>()
+            .add_input_modifier::<Charge>()
             .add_input_modifier::<Clamp>()
             .add_input_modifier::<DeadZone>()
+            .add_input_modifier::<DeadZoneBySetting>()
             .add_input_modifier::<DeltaScale>()
+            .add_input_modifier::<Derivative>()
             .add_input_modifier::<ExponentialCurve>()
+            .add_input_modifier::<ExponentialMovingAverage>()
+            .add_input_modifier::<Integrate>()
             .add_input_modifier::<LinearStep>()
+            .add_input_modifier::<MouseAcceleration>()
+            .add_input_modifier::<ModifierStack>()
+            .add_input_modifier::<MovingAverage>()
             .add_input_modifier::<Negate>()
+            .add_input_modifier::<NegateBySetting>()
+            .add_input_modifier::<OneEuroFilter>()
+            .add_input_modifier::<ResponseCurve>()
+            .add_input_modifier::<RotateByEntity>()
             .add_input_modifier::<Scale>()
+            .add_input_modifier::<ScaleBySetting>()
             .add_input_modifier::<SmoothNudge>()
+            .add_input_modifier::<SnapDirection>()
+            .add_input_modifier::<StepRepeat>()
             .add_input_modifier::<SwizzleAxis>()
             .configure_sets(
                 PreUpdate,
@@ -456,7 +512,12 @@ impl Plugin for EnhancedInputPlugin {
             )
             .add_systems(
                 PreUpdate,
-                input_reader::update_pending.in_set(EnhancedInputSystems::Prepare),
+                (
+                    input_reader::update_pending,
+                    motion_sensor::update_sensors,
+                    dependency::clear_evaluated,
+                )
+                    .in_set(EnhancedInputSystems::Prepare),
             );
     }
 
@@ -476,8 +537,13 @@ impl Plugin for EnhancedInputPlugin {
             .remove_resource::<ModifierRegistry>()
             .expect("conditions registry should be inserted in `build`");
 
+        let world = app
+            .world_mut()
+            .remove_resource::<InputWorldRegistry>()
+            .expect("input world registry should be inserted in `build`");
+
         for contexts in &*context {
-            contexts.setup(app, &conditions, &modifiers);
+            contexts.setup(app, &conditions, &modifiers, &world);
         }
     }
 }
@@ -485,7 +551,8 @@ impl Plugin for EnhancedInputPlugin {
 /// Label for the system that updates input context instances.
 #[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
 pub enum EnhancedInputSystems {
-    /// Updates list of pending inputs to ignore.
+    /// Updates list of pending inputs to ignore and the data of custom inputs,
+    /// such as the samples from [`MotionSensor`].
     ///
     /// Runs in [`PreUpdate`].
     Prepare,
@@ -496,6 +563,8 @@ pub enum EnhancedInputSystems {
     /// Applies the value from [`ActionValue`] to [`Action`] and triggers
     /// events evaluated from [`Self::Update`].
     ///
+    /// Also writes [`ActionEventMessage`] if it's registered.
+    ///
     /// Executes in every schedule where a context is registered.
     Apply,
 }
@@ -62,15 +62,28 @@ world.spawn((
 */
 
 pub mod accumulate_by;
+pub mod aim_assist;
 pub mod clamp;
 pub mod dead_zone;
 pub mod delta_scale;
+pub mod derivative;
+pub mod erased;
 pub mod exponential_curve;
+pub mod exponential_moving_average;
 pub mod fns;
+pub mod input_settings;
+pub mod integrate;
 pub mod linear_step;
+pub mod mouse_acceleration;
+pub mod moving_average;
 pub mod negate;
+pub mod one_euro_filter;
+pub mod response_curve;
+pub mod rotate_by_entity;
 pub mod scale;
 pub mod smooth_nudge;
+pub mod snap_direction;
+pub mod stack;
 pub mod swizzle_axis;
 
 use core::fmt::Debug;
@@ -85,7 +98,8 @@ use crate::prelude::*;
 /// Can be attached both to bindings and actions.
 ///
 /// If you create a custom modifier, it needs to be registered using
-/// [`InputModifierAppExt::add_input_modifier`].
+/// [`InputModifierAppExt::add_input_modifier`]. To make it serializable inside [`ModifierStack`],
+/// also register [`ReflectInputModifier`] with `#[reflect(InputModifier)]`.
 pub trait InputModifier: Debug {
     /// Returns pre-processed value.
     ///
@@ -96,4 +110,16 @@ pub trait InputModifier: Debug {
         time: &ContextTime,
         value: ActionValue,
     ) -> ActionValue;
+
+    /// Resets the internal state.
+    ///
+    /// Called when the action state returns to [`TriggerState::None`].
+    /// Useful for modifiers that accumulate state across frames, such as filters.
+    fn reset(&mut self) {}
+
+    /// Reads data from the world before [`Self::transform`].
+    ///
+    /// Only resources and components registered with [`InputWorldAppExt`] are accessible.
+    /// Store the data you need in the modifier and use it in [`Self::transform`].
+    fn read_world(&mut self, _world: &InputWorld) {}
 }
@@ -44,7 +44,11 @@ use crate::prelude::*;
 /// struct Movement;
 /// ```
 #[derive(Component, Debug, Clone, Copy)]
-#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputModifier)
+)]
 pub struct AccumulateBy {
     /// Action that activates accumulation.
     action: Entity,
@@ -0,0 +1,467 @@
+use alloc::vec::Vec;
+
+use bevy::{
+    math::curve::{Curve, EaseFunction},
+    prelude::*,
+};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/**
+Targets for aim assist modifiers, such as [`AimSlowdown`] and [`AimMagnetism`].
+
+Fill it from your own query before [`EnhancedInputSystems::Update`], usually every frame.
+The modifiers only read this resource, so it can be populated with mocked positions in tests.
+
+The resource is initialized by the plugin, but needs to be registered with
+[`InputWorldAppExt::add_input_resource`] to be readable by the modifiers.
+It's opt-in to avoid conflicts with systems that mutate it when aim assist isn't used.
+
+# Examples
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+# let mut app = App::new();
+# app.add_plugins(EnhancedInputPlugin);
+app.add_input_resource::<AimTargets>().add_systems(
+    PreUpdate,
+    update_targets.before(EnhancedInputSystems::Update),
+);
+
+fn update_targets(
+    mut targets: ResMut<AimTargets>,
+    camera: Single<&GlobalTransform, With<Camera>>,
+    enemies: Query<&GlobalTransform, With<Enemy>>,
+) {
+    targets.clear();
+    for enemy in &enemies {
+        targets.push(AimTarget::from_view(&camera, enemy.translation()));
+    }
+}
+
+#[derive(Component)]
+struct Enemy;
+```
+*/
+#[derive(Resource, Debug, Default, Clone, Deref, DerefMut)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Default, Resource)
+)]
+pub struct AimTargets(pub Vec<AimTarget>);
+
+/// A target for aim assist modifiers, stored in [`AimTargets`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Default, PartialEq)
+)]
+pub struct AimTarget {
+    /// Screen-space offset of the target from the aim point, with Y pointing up.
+    ///
+    /// Can be in any units, such as pixels, as long as [`AimAssistZone::radius`] uses the same units.
+    /// [`Self::from_view`] uses angles in radians, which don't depend on the resolution.
+    pub offset: Vec2,
+
+    /// Distance from the camera to the target in world units.
+    pub distance: f32,
+}
+
+impl AimTarget {
+    #[must_use]
+    pub const fn new(offset: Vec2, distance: f32) -> Self {
+        Self { offset, distance }
+    }
+
+    /// Creates a new instance from the transform of the camera and the world position of the target.
+    ///
+    /// The offset is stored as the yaw and pitch in radians from the camera forward direction.
+    #[must_use]
+    pub fn from_view(view: &GlobalTransform, position: Vec3) -> Self {
+        let local = view.affine().inverse().transform_point3(position);
+        let forward = -local.z;
+        Self {
+            offset: Vec2::new(ops::atan2(local.x, forward), ops::atan2(local.y, forward)),
+            distance: local.length(),
+        }
+    }
+}
+
+/// Defines how the strength of aim assist depends on the angle and the distance to a target.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Default, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct AimAssistZone {
+    /// Maximum length of [`AimTarget::offset`] at which the target is assisted.
+    ///
+    /// By default set to 0.1, which is about 6 degrees for [`AimTarget::from_view`].
+    pub radius: f32,
+
+    /// Shape of the falloff from the aim point to [`Self::radius`].
+    ///
+    /// Sampled with the offset length divided by the radius.
+    /// The strength is 1 minus the sampled value.
+    ///
+    /// By default set to [`EaseFunction::Linear`].
+    pub falloff: CurveShape,
+
+    /// Distance up to which the target is assisted with the full strength.
+    ///
+    /// By default set to [`f32::INFINITY`].
+    pub fade_distance: f32,
+
+    /// Distance at which the strength fades to zero.
+    ///
+    /// Between [`Self::fade_distance`] and this value, the strength decreases linearly.
+    ///
+    /// By default set to [`f32::INFINITY`].
+    pub max_distance: f32,
+}
+
+impl AimAssistZone {
+    #[must_use]
+    pub const fn new(radius: f32) -> Self {
+        Self {
+            radius,
+            falloff: CurveShape::Ease(EaseFunction::Linear),
+            fade_distance: f32::INFINITY,
+            max_distance: f32::INFINITY,
+        }
+    }
+
+    #[must_use]
+    pub fn with_falloff(mut self, falloff: CurveShape) -> Self {
+        self.falloff = falloff;
+        self
+    }
+
+    /// Fades the strength from `fade_distance` to zero at `max_distance`.
+    #[must_use]
+    pub const fn with_distance(mut self, fade_distance: f32, max_distance: f32) -> Self {
+        self.fade_distance = fade_distance;
+        self.max_distance = max_distance;
+        self
+    }
+
+    /// Returns the strength multiplier from 0.0 to 1.0 for the target.
+    #[must_use]
+    pub fn weight(&self, target: &AimTarget) -> f32 {
+        if self.radius <= 0.0 {
+            return 0.0;
+        }
+
+        let t = target.offset.length() / self.radius;
+        if t >= 1.0 {
+            return 0.0;
+        }
+
+        let angle_weight = 1.0 - self.falloff.sample_clamped(t);
+        angle_weight * self.distance_weight(target.distance)
+    }
+
+    fn distance_weight(&self, distance: f32) -> f32 {
+        if distance <= self.fade_distance {
+            1.0
+        } else if distance >= self.max_distance {
+            0.0
+        } else {
+            1.0 - (distance - self.fade_distance) / (self.max_distance - self.fade_distance)
+        }
+    }
+}
+
+impl Default for AimAssistZone {
+    fn default() -> Self {
+        Self::new(0.1)
+    }
+}
+
+/**
+Reduces the input near [`AimTargets`], also known as aim friction.
+
+Makes it easier to keep the aim on a target without affecting the sensitivity elsewhere.
+The input is multiplied by `1.0 - strength * weight`, where the weight is the highest
+[`AimAssistZone::weight`] among all targets. Requires [`AimTargets`] to be registered for [`InputWorld`].
+
+Intended for gamepad look actions. To keep other inputs, such as the mouse, unaffected,
+attach it to the gamepad bindings instead of the action.
+
+[`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+
+# Examples
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+# let mut world = World::new();
+world.spawn((
+    Player,
+    actions!(Player[
+        (
+            Action::<Look>::new(),
+            Bindings::spawn((
+                Spawn(Binding::mouse_motion()),
+                Axial::right_stick().with((
+                    AimSlowdown::new(0.5),
+                    AimMagnetism::new(0.2),
+                )),
+            )),
+        ),
+    ]),
+));
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Look;
+```
+*/
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, InputModifier)
+)]
+pub struct AimSlowdown {
+    /// Fraction by which the input is reduced at the full weight.
+    ///
+    /// By default set to 0.4.
+    pub strength: f32,
+
+    /// How the weight depends on the target.
+    pub zone: AimAssistZone,
+
+    factor: f32,
+}
+
+impl AimSlowdown {
+    #[must_use]
+    pub const fn new(strength: f32) -> Self {
+        Self {
+            strength,
+            zone: AimAssistZone::new(0.1),
+            factor: 1.0,
+        }
+    }
+
+    #[must_use]
+    pub fn with_zone(mut self, zone: AimAssistZone) -> Self {
+        self.zone = zone;
+        self
+    }
+
+    /// Returns the multiplier for the input from the last read targets.
+    #[must_use]
+    pub fn factor(&self) -> f32 {
+        self.factor
+    }
+}
+
+impl Default for AimSlowdown {
+    fn default() -> Self {
+        Self::new(0.4)
+    }
+}
+
+impl InputModifier for AimSlowdown {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        if let ActionValue::Bool(value) = value {
+            let value = if value { 1.0 } else { 0.0 };
+            return self.transform(_actions, _time, value.into());
+        }
+
+        ActionValue::Axis3D(value.as_axis3d() * self.factor).convert(value.dim())
+    }
+
+    fn read_world(&mut self, world: &InputWorld) {
+        let weight = world
+            .resource::<AimTargets>()
+            .into_iter()
+            .flat_map(|targets| targets.iter())
+            .map(|target| self.zone.weight(target))
+            .fold(0.0, f32::max);
+
+        self.factor = 1.0 - self.strength * weight;
+    }
+}
+
+/**
+Pulls the input toward the closest of [`AimTargets`], also known as aim magnetism.
+
+Adds a bias toward the target with the highest [`AimAssistZone::weight`] to the X and Y axes.
+The bias is `strength * weight` multiplied by the input magnitude,
+so the aim is pulled only while the player is moving it. Requires [`AimTargets`] to be registered for [`InputWorld`].
+
+Intended for gamepad look actions. To keep other inputs, such as the mouse, unaffected,
+attach it to the gamepad bindings instead of the action. See [`AimSlowdown`] for an example.
+
+[`ActionValue::Bool`] and [`ActionValue::Axis1D`] will be transformed into [`ActionValue::Axis2D`].
+*/
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, InputModifier)
+)]
+pub struct AimMagnetism {
+    /// Bias toward the target relative to the input magnitude at the full weight.
+    ///
+    /// By default set to 0.3.
+    pub strength: f32,
+
+    /// How the weight depends on the target.
+    pub zone: AimAssistZone,
+
+    pull: Vec2,
+}
+
+impl AimMagnetism {
+    #[must_use]
+    pub const fn new(strength: f32) -> Self {
+        Self {
+            strength,
+            zone: AimAssistZone::new(0.1),
+            pull: Vec2::ZERO,
+        }
+    }
+
+    #[must_use]
+    pub fn with_zone(mut self, zone: AimAssistZone) -> Self {
+        self.zone = zone;
+        self
+    }
+
+    /// Returns the bias per unit of input from the last read targets.
+    #[must_use]
+    pub fn pull(&self) -> Vec2 {
+        self.pull
+    }
+}
+
+impl Default for AimMagnetism {
+    fn default() -> Self {
+        Self::new(0.3)
+    }
+}
+
+impl InputModifier for AimMagnetism {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        let dim = value.dim();
+        let value = value.as_axis3d();
+        let xy = value.xy() + self.pull * value.xy().length();
+        match dim {
+            ActionValueDim::Axis3D => xy.extend(value.z).into(),
+            _ => xy.into(),
+        }
+    }
+
+    fn read_world(&mut self, world: &InputWorld) {
+        self.pull = world
+            .resource::<AimTargets>()
+            .into_iter()
+            .flat_map(|targets| targets.iter())
+            .map(|target| (self.zone.weight(target), target))
+            .filter(|&(weight, _)| weight > 0.0)
+            .max_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(weight, target)| target.offset.normalize_or_zero() * weight * self.strength)
+            .unwrap_or_default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f32::consts::FRAC_PI_4;
+
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn angle_weight() {
+        let zone = AimAssistZone::new(0.2);
+        assert_eq!(zone.weight(&AimTarget::new(Vec2::ZERO, 10.0)), 1.0);
+        assert_eq!(zone.weight(&AimTarget::new(Vec2::new(0.0, 0.1), 10.0)), 0.5);
+        assert_eq!(zone.weight(&AimTarget::new(Vec2::new(0.3, 0.0), 10.0)), 0.0);
+
+        let zone = zone.with_falloff(CurveShape::Ease(EaseFunction::QuadraticIn));
+        assert_eq!(
+            zone.weight(&AimTarget::new(Vec2::new(0.1, 0.0), 10.0)),
+            0.75
+        );
+    }
+
+    #[test]
+    fn distance_weight() {
+        let zone = AimAssistZone::new(0.2).with_distance(10.0, 30.0);
+        assert_eq!(zone.weight(&AimTarget::new(Vec2::ZERO, 5.0)), 1.0);
+        assert_eq!(zone.weight(&AimTarget::new(Vec2::ZERO, 20.0)), 0.5);
+        assert_eq!(zone.weight(&AimTarget::new(Vec2::ZERO, 40.0)), 0.0);
+    }
+
+    #[test]
+    fn from_view() {
+        let view = GlobalTransform::from(Transform::from_xyz(0.0, 0.0, 10.0));
+
+        let target = AimTarget::from_view(&view, Vec3::ZERO);
+        assert_eq!(target, AimTarget::new(Vec2::ZERO, 10.0));
+
+        let target = AimTarget::from_view(&view, Vec3::new(10.0, 0.0, 0.0));
+        assert!(target.offset.abs_diff_eq(Vec2::new(FRAC_PI_4, 0.0), 1e-6));
+
+        let target = AimTarget::from_view(&view, Vec3::new(0.0, 0.0, 20.0));
+        assert_eq!(
+            AimAssistZone::default().weight(&target),
+            0.0,
+            "targets behind the camera shouldn't be assisted"
+        );
+    }
+
+    #[test]
+    fn transform_without_targets() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut slowdown = AimSlowdown::default();
+        assert_eq!(slowdown.transform(&actions, &time, true.into()), 1.0.into());
+        assert_eq!(
+            slowdown.transform(&actions, &time, Vec2::ONE.into()),
+            Vec2::ONE.into()
+        );
+
+        let mut magnetism = AimMagnetism::default();
+        assert_eq!(
+            magnetism.transform(&actions, &time, 1.0.into()),
+            Vec2::X.into()
+        );
+        assert_eq!(
+            magnetism.transform(&actions, &time, Vec3::ONE.into()),
+            Vec3::ONE.into()
+        );
+    }
+}
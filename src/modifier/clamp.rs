@@ -39,7 +39,11 @@ use crate::prelude::*;
 /// struct Down;
 /// ```
 #[derive(Component, Debug, Clone, Copy)]
-#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputModifier)
+)]
 pub struct Clamp {
     /// Minimum value per axis.
     pub min: Vec3,
@@ -1,3 +1,5 @@
+use core::f32::consts::FRAC_PI_4;
+
 use bevy::prelude::*;
 
 use crate::prelude::*;
@@ -10,9 +12,16 @@ use crate::prelude::*;
 ///
 /// Useful to ensure consistent diagonal movement speed.
 ///
+/// Besides the shape from [`DeadZoneKind`], [`Self::upper_threshold`] can be lowered to create an outer zone
+/// that snaps to 1, and [`Self::anti_dead_zone`] can be used to counter a dead zone applied by the game itself.
+///
 /// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
 #[derive(Component, Debug, Clone, Copy)]
-#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputModifier)
+)]
 pub struct DeadZone {
     /// Defines how axes are processed.
     ///
@@ -28,6 +37,14 @@ pub struct DeadZone {
     ///
     /// By default set to 1.0.
     pub upper_threshold: f32,
+
+    /// Minimum output for input outside the dead zone.
+    ///
+    /// Output is remapped from 0 -> 1 to this value -> 1, while input inside the dead zone still outputs 0.
+    /// Useful to counter an additional dead zone applied later, such as the in-game dead zone of a remote game.
+    ///
+    /// By default set to 0.0.
+    pub anti_dead_zone: f32,
 }
 
 impl DeadZone {
@@ -37,15 +54,53 @@ impl DeadZone {
             kind,
             lower_threshold: 0.2,
             upper_threshold: 1.0,
+            anti_dead_zone: 0.0,
         }
     }
 
     fn dead_zone(self, axis_value: f32) -> f32 {
+        let scaled_value = self.scale(axis_value);
+        if scaled_value == 0.0 {
+            return 0.0;
+        }
+
+        let anti_dead_zone = self.anti_dead_zone.clamp(0.0, 1.0);
+        (anti_dead_zone + (1.0 - anti_dead_zone) * scaled_value.abs()).copysign(scaled_value)
+    }
+
+    /// Like [`Self::dead_zone`], but without [`Self::anti_dead_zone`].
+    fn scale(self, axis_value: f32) -> f32 {
         // Translate and scale the input to the +/- 1 range after removing the dead zone.
         let lower_bound = (axis_value.abs() - self.lower_threshold).max(0.0);
         let scaled_value = lower_bound / (self.upper_threshold - self.lower_threshold);
         scaled_value.min(1.0) * axis_value.signum()
     }
+
+    fn bowtie(self, value: Vec2, angle: f32) -> Vec2 {
+        let abs = value.abs();
+        let magnitude = self.dead_zone(abs.length());
+        if magnitude == 0.0 {
+            return Vec2::ZERO;
+        }
+
+        // Angle from the nearest axis in the 0 -> PI/4 range.
+        let offset = ops::atan2(abs.min_element(), abs.max_element());
+        let angle = angle.clamp(0.0, FRAC_PI_4);
+        let offset = if offset <= angle {
+            0.0
+        } else {
+            // Remap the remaining range to keep the output continuous.
+            (offset - angle) / (FRAC_PI_4 - angle) * FRAC_PI_4
+        };
+
+        let (sin, cos) = ops::sin_cos(offset);
+        let direction = if abs.x >= abs.y {
+            Vec2::new(cos, sin)
+        } else {
+            Vec2::new(sin, cos)
+        };
+        (direction * magnitude).copysign(value)
+    }
 }
 
 impl Default for DeadZone {
@@ -76,9 +131,14 @@ impl InputModifier for DeadZone {
                     value.y = self.dead_zone(value.y);
                     value.into()
                 }
+                DeadZoneKind::Hybrid => {
+                    let direction = value.map(|value| self.scale(value)).normalize_or_zero();
+                    (direction * self.dead_zone(value.length())).into()
+                }
+                DeadZoneKind::Bowtie { angle } => self.bowtie(value, angle).into(),
             },
             ActionValue::Axis3D(mut value) => match self.kind {
-                DeadZoneKind::Radial => {
+                DeadZoneKind::Radial | DeadZoneKind::Bowtie { .. } => {
                     (value.normalize_or_zero() * self.dead_zone(value.length())).into()
                 }
                 DeadZoneKind::Axial => {
@@ -87,6 +147,10 @@ impl InputModifier for DeadZone {
                     value.z = self.dead_zone(value.z);
                     value.into()
                 }
+                DeadZoneKind::Hybrid => {
+                    let direction = value.map(|value| self.scale(value)).normalize_or_zero();
+                    (direction * self.dead_zone(value.length())).into()
+                }
             },
         }
     }
@@ -108,6 +172,27 @@ pub enum DeadZoneKind {
     /// This will result in input being chamfered at the corners
     /// for [`ActionValue::Axis2D`]/[`ActionValue::Axis2D`].
     Axial,
+    /// Combines [`Self::Radial`] magnitude with [`Self::Axial`] direction.
+    ///
+    /// The magnitude stays smooth in all directions, while input close to an axis
+    /// snaps to it for cardinal precision.
+    /// For [`ActionValue::Axis1D`] and [`ActionValue::Bool`]
+    /// this works identically to [`Self::Axial`].
+    Hybrid,
+    /// Applies [`Self::Radial`] dead zone and snaps the direction to the nearest axis
+    /// when the input is within [`Self::Bowtie::angle`] from it.
+    ///
+    /// The remaining angles are remapped to keep the output continuous.
+    /// Useful when movement along an axis should stay precise, such as walking straight forward.
+    /// Only X and Y are snapped, for [`ActionValue::Axis3D`] this works identically to [`Self::Radial`].
+    /// For [`ActionValue::Axis1D`] and [`ActionValue::Bool`]
+    /// this works identically to [`Self::Axial`].
+    Bowtie {
+        /// Angle in radians from each axis within which the input snaps to it.
+        ///
+        /// Clamped to the 0 -> PI/4 range.
+        angle: f32,
+    },
 }
 
 #[cfg(test)]
@@ -209,4 +294,129 @@ mod tests {
             Vec3::ZERO.into()
         );
     }
+
+    #[test]
+    fn hybrid() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = DeadZone::new(DeadZoneKind::Hybrid);
+
+        assert_eq!(modifier.transform(&actions, &time, true.into()), 1.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, 0.5.into()),
+            0.375.into()
+        );
+        assert_eq!(modifier.transform(&actions, &time, 0.2.into()), 0.0.into());
+
+        let value = modifier
+            .transform(&actions, &time, Vec2::new(0.5, 0.1).into())
+            .as_axis2d();
+        assert_eq!(value.y, 0.0, "should snap to the axis");
+        assert!((value.x - 0.38737).abs() < 1e-4);
+
+        assert_eq!(
+            modifier.transform(&actions, &time, (Vec2::ONE * 0.15).into()),
+            Vec2::ZERO.into(),
+            "should stay in the dead zone if all axes are inside it"
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::ONE.into()),
+            (Vec2::ONE * 0.70710677).into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec3::new(0.0, 0.0, 2.0).into()),
+            Vec3::Z.into()
+        );
+    }
+
+    #[test]
+    fn bowtie() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = DeadZone::new(DeadZoneKind::Bowtie {
+            angle: FRAC_PI_4 / 2.0,
+        });
+
+        assert_eq!(
+            modifier.transform(&actions, &time, 0.5.into()),
+            0.375.into()
+        );
+
+        let value = modifier
+            .transform(&actions, &time, Vec2::from_angle(0.3).into())
+            .as_axis2d();
+        assert!(value.abs_diff_eq(Vec2::X, 1e-6), "should snap to the axis");
+
+        let value = modifier
+            .transform(&actions, &time, (-Vec2::from_angle(FRAC_PI_4 / 2.0)).into())
+            .as_axis2d();
+        assert!(
+            value.abs_diff_eq(Vec2::NEG_X, 1e-6),
+            "should snap on the boundary"
+        );
+
+        let value = modifier
+            .transform(&actions, &time, Vec2::from_angle(FRAC_PI_4 * 0.75).into())
+            .as_axis2d();
+        assert!(value.abs_diff_eq(Vec2::from_angle(FRAC_PI_4 / 2.0), 1e-6));
+
+        let value = modifier
+            .transform(&actions, &time, Vec2::new(-1.0, 1.0).into())
+            .as_axis2d();
+        assert!(value.abs_diff_eq(Vec2::new(-1.0, 1.0).normalize(), 1e-6));
+
+        assert_eq!(
+            modifier.transform(&actions, &time, (Vec2::Y * 0.2).into()),
+            Vec2::ZERO.into()
+        );
+    }
+
+    #[test]
+    fn anti_dead_zone() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = DeadZone {
+            anti_dead_zone: 0.1,
+            ..Default::default()
+        };
+
+        assert_eq!(modifier.transform(&actions, &time, 0.2.into()), 0.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, 0.5.into()),
+            0.43749997.into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, (-0.5).into()),
+            (-0.43749997).into()
+        );
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 1.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, (Vec2::Y * 0.5).into()),
+            (Vec2::Y * 0.43749997).into()
+        );
+    }
+
+    #[test]
+    fn outer_zone() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = DeadZone {
+            kind: DeadZoneKind::Axial,
+            lower_threshold: 0.1,
+            upper_threshold: 0.9,
+            ..Default::default()
+        };
+
+        assert_eq!(modifier.transform(&actions, &time, 0.9.into()), 1.0.into());
+        assert_eq!(modifier.transform(&actions, &time, 0.95.into()), 1.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, 0.5.into()),
+            0.50000006.into()
+        );
+        assert_eq!(modifier.transform(&actions, &time, 0.1.into()), 0.0.into());
+    }
 }
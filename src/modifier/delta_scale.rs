@@ -9,7 +9,7 @@ use crate::prelude::*;
 #[cfg_attr(
     feature = "reflect",
     derive(Reflect),
-    reflect(Clone, Component, Debug, Default)
+    reflect(Clone, Component, Debug, Default, InputModifier)
 )]
 pub struct DeltaScale {
     /// The type of time used to scale the input by.
@@ -0,0 +1,129 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Outputs the rate of change of the input per second.
+///
+/// Useful for flick-to-turn cameras or gesture speed.
+///
+/// The first value produces zero, since there is nothing to compare it with.
+/// The same applies after the value dimension changes.
+///
+/// The last value is kept when the action returns to [`TriggerState::None`],
+/// so the first motion after it is compared with the resting value and isn't lost.
+///
+/// See also [`Integrate`] for the opposite operation.
+///
+/// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+#[derive(Component, Debug, Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, InputModifier)
+)]
+pub struct Derivative {
+    /// The type of time used to calculate the rate.
+    pub time_kind: TimeKind,
+
+    previous: Option<(ActionValueDim, Vec3)>,
+}
+
+impl Derivative {
+    #[must_use]
+    pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+}
+
+impl InputModifier for Derivative {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        if let ActionValue::Bool(value) = value {
+            let value = if value { 1.0 } else { 0.0 };
+            return self.transform(_actions, time, value.into());
+        }
+
+        let dim = value.dim();
+        let delta_secs = time.delta_kind(self.time_kind).as_secs_f32();
+        if delta_secs <= 0.0 {
+            return ActionValue::zero(dim);
+        }
+
+        let current = value.as_axis3d();
+        let rate = match self.previous.replace((dim, current)) {
+            Some((previous_dim, previous)) if previous_dim == dim => {
+                (current - previous) / delta_secs
+            }
+            _ => Vec3::ZERO,
+        };
+
+        ActionValue::Axis3D(rate).convert(dim)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn rate() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(100));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = Derivative::default();
+        assert_eq!(modifier.transform(&actions, &time, 0.5.into()), 0.0.into());
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 5.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, false.into()),
+            (-10.0).into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::ONE.into()),
+            Vec2::ZERO.into(),
+            "should reset on dimension change"
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(2.0, 1.0).into()),
+            Vec2::new(10.0, 0.0).into()
+        );
+    }
+
+    #[test]
+    fn reset() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(100));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = Derivative::default();
+        modifier.transform(&actions, &time, 0.0.into());
+        modifier.reset();
+        assert_eq!(
+            modifier.transform(&actions, &time, 1.0.into()),
+            10.0.into(),
+            "motion after a reset should be compared with the last value"
+        );
+    }
+
+    #[test]
+    fn zero_delta() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = Derivative::default();
+        modifier.transform(&actions, &time, 0.0.into());
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 0.0.into());
+    }
+}
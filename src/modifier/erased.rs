@@ -0,0 +1,185 @@
+use alloc::boxed::Box;
+use core::{
+    any::Any,
+    fmt::{self, Debug, Formatter},
+    ops::{Deref, DerefMut},
+};
+
+#[cfg(all(feature = "reflect", feature = "serialize"))]
+use bevy::reflect::{
+    TypeRegistry,
+    serde::{
+        DeserializeWithRegistry, ReflectDeserializeWithRegistry, ReflectSerializeWithRegistry,
+        SerializeWithRegistry,
+    },
+};
+use bevy::{prelude::*, reflect::FromType};
+#[cfg(all(feature = "reflect", feature = "serialize"))]
+use serde::{Deserializer, Serializer};
+
+use crate::prelude::*;
+#[cfg(all(feature = "reflect", feature = "serialize"))]
+use crate::type_registry;
+
+/// A type-erased [`InputModifier`].
+///
+/// Allows storing modifiers of different types in a single component, such as [`ModifierStack`].
+/// Dereferences to the modifier as the trait object.
+///
+/// With the `reflect` and `serialize` features, can be serialized through reflection using the app's
+/// type registry, such as with [`TypedReflectSerializer`](bevy::reflect::serde::TypedReflectSerializer)
+/// or in scenes. Serialized as a map from the modifier type path to its reflected value,
+/// so the type needs to be registered with [`ReflectInputModifier`] using `#[reflect(InputModifier)]`.
+/// All built-in modifiers are registered this way.
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(opaque, Clone, Debug))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(SerializeWithRegistry, DeserializeWithRegistry)
+)]
+pub struct ErasedModifier(Box<dyn DynModifier>);
+
+impl ErasedModifier {
+    /// Creates a new instance from a modifier.
+    #[must_use]
+    pub fn new<C: InputModifier + Clone + Send + Sync + 'static>(modifier: C) -> Self {
+        Self(Box::new(modifier))
+    }
+
+    /// Returns the modifier if it has type `C`.
+    #[must_use]
+    pub fn downcast_ref<C: InputModifier + 'static>(&self) -> Option<&C> {
+        self.as_any().downcast_ref()
+    }
+
+    /// Returns the modifier if it has type `C`.
+    #[must_use]
+    pub fn downcast_mut<C: InputModifier + 'static>(&mut self) -> Option<&mut C> {
+        (&mut *self.0 as &mut dyn Any).downcast_mut()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &*self.0
+    }
+}
+
+impl<C: InputModifier + Clone + Send + Sync + 'static> From<C> for ErasedModifier {
+    fn from(modifier: C) -> Self {
+        Self::new(modifier)
+    }
+}
+
+impl Deref for ErasedModifier {
+    type Target = dyn InputModifier;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.0
+    }
+}
+
+impl DerefMut for ErasedModifier {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut *self.0
+    }
+}
+
+impl Clone for ErasedModifier {
+    fn clone(&self) -> Self {
+        Self(self.0.clone_boxed())
+    }
+}
+
+impl Debug for ErasedModifier {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(all(feature = "reflect", feature = "serialize"))]
+impl SerializeWithRegistry for ErasedModifier {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+        registry: &TypeRegistry,
+    ) -> Result<S::Ok, S::Error> {
+        type_registry::serialize(
+            serializer,
+            registry,
+            Any::type_id(&*self.0),
+            self.0.type_name(),
+            |reflect: &ReflectInputModifier| reflect.as_reflect(self),
+        )
+    }
+}
+
+#[cfg(all(feature = "reflect", feature = "serialize"))]
+impl<'de> DeserializeWithRegistry<'de> for ErasedModifier {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+        registry: &TypeRegistry,
+    ) -> Result<Self, D::Error> {
+        type_registry::deserialize(
+            deserializer,
+            registry,
+            |reflect: &ReflectInputModifier, value| reflect.from_reflect(value).ok(),
+        )
+    }
+}
+
+/// Object-safe extension of [`InputModifier`] to clone and downcast the erased modifier.
+trait DynModifier: InputModifier + Any + Send + Sync {
+    fn clone_boxed(&self) -> Box<dyn DynModifier>;
+
+    #[cfg(all(feature = "reflect", feature = "serialize"))]
+    fn type_name(&self) -> &'static str;
+}
+
+impl<C: InputModifier + Clone + Send + Sync + 'static> DynModifier for C {
+    fn clone_boxed(&self) -> Box<dyn DynModifier> {
+        Box::new(self.clone())
+    }
+
+    #[cfg(all(feature = "reflect", feature = "serialize"))]
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<C>()
+    }
+}
+
+/// Type data to convert a reflected modifier to and from [`ErasedModifier`].
+///
+/// Register it with `#[reflect(InputModifier)]` to make the modifier serializable inside [`ModifierStack`].
+#[derive(Clone)]
+pub struct ReflectInputModifier {
+    as_reflect: fn(&ErasedModifier) -> Option<&dyn Reflect>,
+    from_reflect: fn(Box<dyn Reflect>) -> Result<ErasedModifier, Box<dyn Reflect>>,
+}
+
+impl ReflectInputModifier {
+    /// Returns the modifier as [`Reflect`] if it has the type this data was created for.
+    #[must_use]
+    pub fn as_reflect<'a>(&self, modifier: &'a ErasedModifier) -> Option<&'a dyn Reflect> {
+        (self.as_reflect)(modifier)
+    }
+
+    /// Converts a reflected modifier into [`ErasedModifier`].
+    ///
+    /// Returns the value back if it doesn't have the type this data was created for.
+    pub fn from_reflect(
+        &self,
+        reflect: Box<dyn Reflect>,
+    ) -> Result<ErasedModifier, Box<dyn Reflect>> {
+        (self.from_reflect)(reflect)
+    }
+}
+
+impl<C: InputModifier + Reflect + Clone> FromType<C> for ReflectInputModifier {
+    fn from_type() -> Self {
+        Self {
+            as_reflect: |modifier| {
+                modifier
+                    .downcast_ref::<C>()
+                    .map(|modifier| modifier as &dyn Reflect)
+            },
+            from_reflect: |reflect| reflect.downcast::<C>().map(|modifier| (*modifier).into()),
+        }
+    }
+}
@@ -8,7 +8,11 @@ use crate::prelude::*;
 ///
 /// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
 #[derive(Component, Debug, Clone, Copy)]
-#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputModifier)
+)]
 pub struct ExponentialCurve {
     /// Curve exponent.
     pub exp: Vec3,
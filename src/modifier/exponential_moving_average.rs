@@ -0,0 +1,137 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Smooths input with an exponential moving average over time.
+///
+/// Unlike [`SmoothNudge`], the state is reset when the action returns to [`TriggerState::None`]
+/// or the value dimension changes, and the first value after a reset passes through unchanged.
+/// This avoids easing in from the previous value when the input starts again.
+///
+/// The smoothing is independent of the frame rate.
+///
+/// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, InputModifier)
+)]
+pub struct ExponentialMovingAverage {
+    /// Time in seconds for the average to cover about 63% of a change in the input.
+    ///
+    /// Higher values produce smoother output, but increase the lag.
+    ///
+    /// By default set to 0.05.
+    pub time_constant: f32,
+
+    /// The type of time used to advance the average.
+    pub time_kind: TimeKind,
+
+    state: Option<(ActionValueDim, Vec3)>,
+}
+
+impl ExponentialMovingAverage {
+    #[must_use]
+    pub fn new(time_constant: f32) -> Self {
+        Self {
+            time_constant,
+            time_kind: Default::default(),
+            state: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+}
+
+impl Default for ExponentialMovingAverage {
+    fn default() -> Self {
+        Self::new(0.05)
+    }
+}
+
+impl InputModifier for ExponentialMovingAverage {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        if let ActionValue::Bool(value) = value {
+            let value = if value { 1.0 } else { 0.0 };
+            return self.transform(_actions, time, value.into());
+        }
+
+        let dim = value.dim();
+        let Some((_, average)) = self.state.as_mut().filter(|&&mut (d, _)| d == dim) else {
+            self.state = Some((dim, value.as_axis3d()));
+            return value;
+        };
+
+        let delta_secs = time.delta_kind(self.time_kind).as_secs_f32();
+        let factor = if self.time_constant > 0.0 {
+            1.0 - ops::exp(-delta_secs / self.time_constant)
+        } else {
+            1.0
+        };
+        *average = average.lerp(value.as_axis3d(), factor);
+
+        ActionValue::Axis3D(*average).convert(dim)
+    }
+
+    fn reset(&mut self) {
+        self.state = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn average() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(100));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = ExponentialMovingAverage::new(0.1);
+        assert_eq!(modifier.transform(&actions, &time, 0.0.into()), 0.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, 1.0.into()),
+            0.63212055.into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, true.into()),
+            0.8646647.into()
+        );
+    }
+
+    #[test]
+    fn reset() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(100));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = ExponentialMovingAverage::new(0.1);
+        modifier.transform(&actions, &time, 0.0.into());
+        modifier.reset();
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 1.0.into());
+
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::ONE.into()),
+            Vec2::ONE.into(),
+            "should reset on dimension change"
+        );
+    }
+}
@@ -71,6 +71,15 @@ pub(crate) struct ModifierRegistry(Vec<ComponentId>);
 #[derive(Component, Deref, Default)]
 pub(crate) struct ModifierFns(Vec<GetModifierFn>);
 
+impl ModifierFns {
+    /// Calls [`InputModifier::reset`] for all modifiers on the entity.
+    pub(crate) fn reset(&self, entity: &mut FilteredEntityMut) {
+        for get_modifier in &self.0 {
+            get_modifier(entity).reset();
+        }
+    }
+}
+
 type GetModifierFn = for<'a> fn(&'a mut FilteredEntityMut) -> &'a mut dyn InputModifier;
 
 fn get_modifier<'a, C: InputModifier + Component<Mutability = Mutable>>(
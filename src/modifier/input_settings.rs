@@ -0,0 +1,310 @@
+use alloc::{borrow::Cow, collections::BTreeMap, string::String};
+
+use bevy::prelude::*;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/**
+Player settings, such as sensitivity or inversion, shared across actions.
+
+Stores named scalar and bool values that modifiers can reference by key with [`SettingRef`]
+instead of a literal. Read by [`ScaleBySetting`], [`NegateBySetting`] and [`DeadZoneBySetting`].
+Settings are read on every evaluation, so changes to this resource apply immediately.
+
+If a referenced setting is missing, the modifier falls back to its configured value.
+
+The resource is initialized by the plugin, but needs to be registered with
+[`InputWorldAppExt::add_input_resource`] to be readable by the modifiers.
+
+Can be serialized, so it can be stored with the rest of the game settings.
+
+# Examples
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+# let mut app = App::new();
+# app.add_plugins(EnhancedInputPlugin);
+app.add_input_resource::<InputSettings>().insert_resource(
+    InputSettings::default()
+        .with_scalar("look_sensitivity", 1.5)
+        .with_bool("invert_y", true),
+);
+
+# let mut world = World::new();
+world.spawn((
+    Player,
+    actions!(Player[
+        (
+            Action::<Look>::new(),
+            ScaleBySetting::new("look_sensitivity"),
+            NegateBySetting::new(Negate::y(), "invert_y"),
+            Bindings::spawn((
+                Spawn((Binding::mouse_motion(), Scale::splat(0.1))),
+                Axial::right_stick(),
+            )),
+        ),
+    ]),
+));
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Look;
+```
+*/
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Default, PartialEq, Resource)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct InputSettings {
+    /// Scalar settings, such as sensitivity.
+    pub scalars: BTreeMap<String, f32>,
+
+    /// Bool settings, such as inversion.
+    pub bools: BTreeMap<String, bool>,
+}
+
+impl InputSettings {
+    #[must_use]
+    pub fn with_scalar(mut self, key: impl Into<String>, value: f32) -> Self {
+        self.set_scalar(key, value);
+        self
+    }
+
+    #[must_use]
+    pub fn with_bool(mut self, key: impl Into<String>, value: bool) -> Self {
+        self.set_bool(key, value);
+        self
+    }
+
+    /// Returns the scalar setting for the key.
+    #[must_use]
+    pub fn scalar(&self, key: &str) -> Option<f32> {
+        self.scalars.get(key).copied()
+    }
+
+    /// Returns the bool setting for the key.
+    #[must_use]
+    pub fn bool(&self, key: &str) -> Option<bool> {
+        self.bools.get(key).copied()
+    }
+
+    pub fn set_scalar(&mut self, key: impl Into<String>, value: f32) {
+        self.scalars.insert(key.into(), value);
+    }
+
+    pub fn set_bool(&mut self, key: impl Into<String>, value: bool) {
+        self.bools.insert(key.into(), value);
+    }
+}
+
+/// Reference to a value in [`InputSettings`] by key.
+///
+/// The value is read before each evaluation of the modifier that stores it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, PartialEq),
+    reflect(where T: Clone + core::fmt::Debug + PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+#[cfg_attr(feature = "serialize", serde(bound = ""))]
+pub struct SettingRef<T> {
+    /// Key of the setting.
+    pub key: Cow<'static, str>,
+
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    value: Option<T>,
+}
+
+impl<T: Copy> SettingRef<T> {
+    #[must_use]
+    pub fn new(key: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            key: key.into(),
+            value: None,
+        }
+    }
+
+    /// Returns the value from the last read.
+    ///
+    /// Returns [`None`] if the setting is missing or wasn't read yet.
+    #[must_use]
+    pub fn value(&self) -> Option<T> {
+        self.value
+    }
+}
+
+impl SettingRef<f32> {
+    pub(crate) fn read(&mut self, world: &InputWorld) {
+        self.value = world
+            .resource::<InputSettings>()
+            .and_then(|settings| settings.scalar(&self.key));
+    }
+}
+
+impl SettingRef<bool> {
+    pub(crate) fn read(&mut self, world: &InputWorld) {
+        self.value = world
+            .resource::<InputSettings>()
+            .and_then(|settings| settings.bool(&self.key));
+    }
+}
+
+/// Scales input on all axes by a scalar from [`InputSettings`].
+///
+/// If the setting is missing, the value passes unchanged.
+/// Can be combined with [`Scale`] to apply a base factor.
+///
+/// [`ActionValue::Bool`] will be converted into [`ActionValue::Axis1D`].
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputModifier)
+)]
+pub struct ScaleBySetting {
+    /// Scalar setting that multiplies the input value.
+    pub setting: SettingRef<f32>,
+}
+
+impl ScaleBySetting {
+    #[must_use]
+    pub fn new(key: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            setting: SettingRef::new(key),
+        }
+    }
+}
+
+impl InputModifier for ScaleBySetting {
+    fn transform(
+        &mut self,
+        actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        let factor = self.setting.value().unwrap_or(1.0);
+        Scale::splat(factor).transform(actions, time, value)
+    }
+
+    fn read_world(&mut self, world: &InputWorld) {
+        self.setting.read(world);
+    }
+}
+
+/// Applies [`Negate`] only if a bool from [`InputSettings`] is `true`.
+///
+/// If the setting is missing, [`Self::negate`] is always applied.
+///
+/// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputModifier)
+)]
+pub struct NegateBySetting {
+    /// Axes to invert when the setting is enabled.
+    pub negate: Negate,
+
+    /// Bool setting that enables the inversion.
+    pub setting: SettingRef<bool>,
+}
+
+impl NegateBySetting {
+    #[must_use]
+    pub fn new(negate: Negate, key: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            negate,
+            setting: SettingRef::new(key),
+        }
+    }
+}
+
+impl InputModifier for NegateBySetting {
+    fn transform(
+        &mut self,
+        actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        let mut negate = if self.setting.value().unwrap_or(true) {
+            self.negate
+        } else {
+            Negate::none()
+        };
+        negate.transform(actions, time, value)
+    }
+
+    fn read_world(&mut self, world: &InputWorld) {
+        self.setting.read(world);
+    }
+}
+
+/// Applies [`DeadZone`] with the lower threshold from [`InputSettings`].
+///
+/// If the setting is missing, [`DeadZone::lower_threshold`] from [`Self::dead_zone`] is used.
+///
+/// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputModifier)
+)]
+pub struct DeadZoneBySetting {
+    /// Dead zone to apply.
+    pub dead_zone: DeadZone,
+
+    /// Scalar setting that overrides [`DeadZone::lower_threshold`].
+    pub lower_threshold: SettingRef<f32>,
+}
+
+impl DeadZoneBySetting {
+    #[must_use]
+    pub fn new(dead_zone: DeadZone, key: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            dead_zone,
+            lower_threshold: SettingRef::new(key),
+        }
+    }
+}
+
+impl InputModifier for DeadZoneBySetting {
+    fn transform(
+        &mut self,
+        actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        let mut dead_zone = self.dead_zone;
+        if let Some(lower_threshold) = self.lower_threshold.value() {
+            dead_zone.lower_threshold = lower_threshold;
+        }
+        dead_zone.transform(actions, time, value)
+    }
+
+    fn read_world(&mut self, world: &InputWorld) {
+        self.lower_threshold.read(world);
+    }
+}
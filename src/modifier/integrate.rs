@@ -0,0 +1,194 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Outputs the integral of the input over time, with optional bounds and decay.
+///
+/// Each update adds the input multiplied by the delta time in seconds.
+/// Unlike [`AccumulateBy`], it doesn't depend on the frame rate and doesn't require another action.
+/// Useful for turning a rate into a position, such as a throttle lever controlled by a stick.
+///
+/// The state is reset when the action returns to [`TriggerState::None`] or the value dimension changes.
+/// With [`Self::decay`] the accumulated value returns to zero over time,
+/// so the action eventually becomes inactive once the input stops.
+///
+/// See also [`Derivative`] for the opposite operation.
+///
+/// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, InputModifier)
+)]
+pub struct Integrate {
+    /// Minimum accumulated value per axis.
+    ///
+    /// By default set to [`f32::MIN`].
+    pub min: Vec3,
+
+    /// Maximum accumulated value per axis.
+    ///
+    /// By default set to [`f32::MAX`].
+    pub max: Vec3,
+
+    /// Rate per second at which the accumulated value decays toward zero.
+    ///
+    /// The value is multiplied by `exp(-decay * delta)` before adding the input.
+    ///
+    /// By default set to 0.0.
+    pub decay: f32,
+
+    /// The type of time used to advance the integral.
+    pub time_kind: TimeKind,
+
+    state: Option<(ActionValueDim, Vec3)>,
+}
+
+impl Integrate {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            min: Vec3::MIN,
+            max: Vec3::MAX,
+            decay: 0.0,
+            time_kind: Default::default(),
+            state: None,
+        }
+    }
+
+    /// Restricts all axes of the accumulated value to `min` and `max`.
+    #[must_use]
+    pub fn with_bounds(mut self, min: f32, max: f32) -> Self {
+        self.min = Vec3::splat(min);
+        self.max = Vec3::splat(max);
+        self
+    }
+
+    #[must_use]
+    pub fn with_decay(mut self, decay: f32) -> Self {
+        self.decay = decay;
+        self
+    }
+
+    #[must_use]
+    pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+}
+
+impl Default for Integrate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InputModifier for Integrate {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        if let ActionValue::Bool(value) = value {
+            let value = if value { 1.0 } else { 0.0 };
+            return self.transform(_actions, time, value.into());
+        }
+
+        let dim = value.dim();
+        let accumulated = match &mut self.state {
+            Some((state_dim, accumulated)) if *state_dim == dim => accumulated,
+            _ => &mut self.state.insert((dim, Vec3::ZERO)).1,
+        };
+
+        let delta_secs = time.delta_kind(self.time_kind).as_secs_f32();
+        if self.decay > 0.0 {
+            *accumulated *= ops::exp(-self.decay * delta_secs);
+        }
+        *accumulated += value.as_axis3d() * delta_secs;
+        *accumulated = accumulated.clamp(self.min, self.max);
+
+        ActionValue::Axis3D(*accumulated).convert(dim)
+    }
+
+    fn reset(&mut self) {
+        self.state = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn integral() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(500));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = Integrate::new();
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 0.5.into());
+        assert_eq!(modifier.transform(&actions, &time, true.into()), 1.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, (-4.0).into()),
+            (-1.0).into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::ONE.into()),
+            Vec2::splat(0.5).into(),
+            "should reset on dimension change"
+        );
+    }
+
+    #[test]
+    fn bounds() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(500));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = Integrate::new().with_bounds(-1.0, 1.0);
+        assert_eq!(modifier.transform(&actions, &time, 4.0.into()), 1.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, (-1.0).into()),
+            0.5.into()
+        );
+    }
+
+    #[test]
+    fn decay() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(500));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = Integrate::new().with_decay(2.0);
+        assert_eq!(modifier.transform(&actions, &time, 2.0.into()), 1.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, 0.0.into()),
+            0.36787945.into()
+        );
+    }
+
+    #[test]
+    fn reset() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(500));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = Integrate::new();
+        modifier.transform(&actions, &time, 1.0.into());
+        modifier.reset();
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 0.5.into());
+    }
+}
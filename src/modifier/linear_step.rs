@@ -7,7 +7,11 @@ use crate::prelude::*;
 ///
 /// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`]
 #[derive(Component, Debug, Clone, Copy)]
-#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputModifier)
+)]
 pub struct LinearStep {
     /// The fraction of the distance to step per frame while accelerating.
     ///
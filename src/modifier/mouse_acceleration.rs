@@ -0,0 +1,249 @@
+use bevy::{
+    math::curve::{Curve, EaseFunction},
+    prelude::*,
+};
+
+use crate::prelude::*;
+
+/**
+Multiplies the input by a gain that depends on the input velocity.
+
+Intended for [`Binding::mouse_motion`], which is read as a delta per frame.
+The velocity is calculated by dividing the delta by the frame time, so slow movements
+keep the precision while fast movements travel further.
+
+The gain goes from [`Self::min_gain`] at rest to [`Self::max_gain`] at [`Self::max_speed`]
+following [`Self::curve`]. Faster movements are capped at [`Self::max_gain`].
+
+With [`CurveMode::PerAxis`] each axis uses its own velocity,
+with [`CurveMode::Radial`] the velocity of the whole vector is used, preserving the direction.
+
+[`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+
+# Examples
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+# let mut world = World::new();
+world.spawn((
+    Player,
+    actions!(Player[
+        (
+            Action::<Rotate>::new(),
+            Bindings::spawn(Spawn((
+                Binding::mouse_motion(),
+                MouseAcceleration::new(2000.0, 2.0).with_mode(CurveMode::Radial),
+                Scale::splat(0.01),
+            ))),
+        ),
+    ]),
+));
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Rotate;
+```
+*/
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, InputModifier)
+)]
+pub struct MouseAcceleration {
+    /// Speed in input units per second at which [`Self::max_gain`] is reached.
+    pub max_speed: f32,
+
+    /// Gain at rest.
+    ///
+    /// By default set to 1.0.
+    pub min_gain: f32,
+
+    /// Gain at [`Self::max_speed`] and above.
+    pub max_gain: f32,
+
+    /// Shape of the gain between [`Self::min_gain`] and [`Self::max_gain`].
+    ///
+    /// Sampled with the speed divided by [`Self::max_speed`].
+    ///
+    /// By default set to [`EaseFunction::Linear`].
+    pub curve: CurveShape,
+
+    /// How the velocity is calculated.
+    ///
+    /// By default set to [`CurveMode::PerAxis`].
+    pub mode: CurveMode,
+
+    /// The type of time used to calculate the velocity.
+    ///
+    /// By default set to [`TimeKind::Real`].
+    pub time_kind: TimeKind,
+}
+
+impl MouseAcceleration {
+    #[must_use]
+    pub fn new(max_speed: f32, max_gain: f32) -> Self {
+        Self {
+            max_speed,
+            min_gain: 1.0,
+            max_gain,
+            curve: CurveShape::Ease(EaseFunction::Linear),
+            mode: CurveMode::PerAxis,
+            time_kind: Default::default(),
+        }
+    }
+
+    #[must_use]
+    pub const fn with_min_gain(mut self, min_gain: f32) -> Self {
+        self.min_gain = min_gain;
+        self
+    }
+
+    #[must_use]
+    pub fn with_curve(mut self, curve: CurveShape) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_mode(mut self, mode: CurveMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+
+    /// Returns the gain for the given speed.
+    #[must_use]
+    pub fn gain(&self, speed: f32) -> f32 {
+        let t = if self.max_speed > 0.0 {
+            speed / self.max_speed
+        } else {
+            1.0
+        };
+        self.min_gain
+            .lerp(self.max_gain, self.curve.sample_clamped(t))
+    }
+}
+
+impl Default for MouseAcceleration {
+    fn default() -> Self {
+        Self::new(2000.0, 2.0)
+    }
+}
+
+impl InputModifier for MouseAcceleration {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        let delta_secs = time.delta_kind(self.time_kind).as_secs_f32();
+        let apply = |value: f32| {
+            if delta_secs <= 0.0 {
+                return value * self.min_gain;
+            }
+            value * self.gain(value.abs() / delta_secs)
+        };
+        let apply_radial = |value: Vec3| {
+            if delta_secs <= 0.0 {
+                return value * self.min_gain;
+            }
+            value * self.gain(value.length() / delta_secs)
+        };
+
+        match value {
+            ActionValue::Bool(value) => {
+                let value = if value { 1.0 } else { 0.0 };
+                apply(value).into()
+            }
+            ActionValue::Axis1D(value) => apply(value).into(),
+            ActionValue::Axis2D(value) => match self.mode {
+                CurveMode::PerAxis => value.map(apply).into(),
+                CurveMode::Radial => apply_radial(value.extend(0.0)).truncate().into(),
+            },
+            ActionValue::Axis3D(value) => match self.mode {
+                CurveMode::PerAxis => value.map(apply).into(),
+                CurveMode::Radial => apply_radial(value).into(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn per_axis() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(10));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = MouseAcceleration::new(1000.0, 3.0);
+        assert_eq!(modifier.transform(&actions, &time, 0.0.into()), 0.0.into());
+        assert_eq!(modifier.transform(&actions, &time, 5.0.into()), 10.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, (-5.0).into()),
+            (-10.0).into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, 20.0.into()),
+            60.0.into(),
+            "should cap at the max gain"
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(5.0, 0.0).into()),
+            Vec2::new(10.0, 0.0).into()
+        );
+    }
+
+    #[test]
+    fn radial() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(10));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = MouseAcceleration::new(1000.0, 3.0).with_mode(CurveMode::Radial);
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(3.0, 4.0).into()),
+            Vec2::new(6.0, 8.0).into()
+        );
+    }
+
+    #[test]
+    fn curve() {
+        let modifier = MouseAcceleration::new(1000.0, 3.0)
+            .with_min_gain(0.5)
+            .with_curve(CurveShape::Ease(EaseFunction::QuadraticIn));
+        assert_eq!(modifier.gain(0.0), 0.5);
+        assert_eq!(modifier.gain(500.0), 1.125);
+        assert_eq!(modifier.gain(2000.0), 3.0);
+    }
+
+    #[test]
+    fn zero_delta() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = MouseAcceleration::new(1000.0, 3.0).with_min_gain(0.5);
+        assert_eq!(modifier.transform(&actions, &time, 4.0.into()), 2.0.into());
+    }
+}
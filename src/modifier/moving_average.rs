@@ -0,0 +1,143 @@
+use alloc::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Averages input values received within a time window.
+///
+/// Produces a stable output for noisy input, but adds lag of about half of the window.
+/// For lower latency, consider [`ExponentialMovingAverage`] or [`OneEuroFilter`].
+///
+/// The state is reset when the action returns to [`TriggerState::None`] or the value dimension changes.
+///
+/// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, InputModifier)
+)]
+pub struct MovingAverage {
+    /// Duration of the window in seconds.
+    ///
+    /// By default set to 0.1.
+    pub window: f32,
+
+    /// The type of time used to advance the window.
+    pub time_kind: TimeKind,
+
+    dim: Option<ActionValueDim>,
+    elapsed: f32,
+    /// Values with the time they were received.
+    samples: VecDeque<(f32, Vec3)>,
+}
+
+impl MovingAverage {
+    #[must_use]
+    pub fn new(window: f32) -> Self {
+        Self {
+            window,
+            time_kind: Default::default(),
+            dim: None,
+            elapsed: 0.0,
+            samples: Default::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+}
+
+impl Default for MovingAverage {
+    fn default() -> Self {
+        Self::new(0.1)
+    }
+}
+
+impl InputModifier for MovingAverage {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        if let ActionValue::Bool(value) = value {
+            let value = if value { 1.0 } else { 0.0 };
+            return self.transform(_actions, time, value.into());
+        }
+
+        let dim = value.dim();
+        if self.dim != Some(dim) {
+            self.reset();
+            self.dim = Some(dim);
+        }
+
+        self.elapsed += time.delta_kind(self.time_kind).as_secs_f32();
+        self.samples.push_back((self.elapsed, value.as_axis3d()));
+        while let Some(&(received, _)) = self.samples.front()
+            && self.elapsed - received >= self.window
+            && self.samples.len() > 1
+        {
+            self.samples.pop_front();
+        }
+
+        let sum: Vec3 = self.samples.iter().map(|&(_, value)| value).sum();
+        ActionValue::Axis3D(sum / self.samples.len() as f32).convert(dim)
+    }
+
+    fn reset(&mut self) {
+        self.dim = None;
+        self.elapsed = 0.0;
+        self.samples.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn window() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(100));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = MovingAverage::new(0.25);
+        assert_eq!(modifier.transform(&actions, &time, 0.0.into()), 0.0.into());
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 0.5.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, true.into()),
+            (2.0 / 3.0).into()
+        );
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 1.0.into());
+    }
+
+    #[test]
+    fn reset() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(100));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = MovingAverage::new(0.25);
+        modifier.transform(&actions, &time, 0.0.into());
+        modifier.reset();
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 1.0.into());
+
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::ONE.into()),
+            Vec2::ONE.into(),
+            "should reset on dimension change"
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use core::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/**
+Smooths input with an adaptive cutoff frequency that increases with speed.
+
+Slow movements are heavily smoothed to remove jitter, while fast movements
+are smoothed less to keep the latency low. Well suited for gyro, touch and noisy analog input.
+For more details read [1€ Filter](https://gery.casiez.net/1euro).
+
+Tune [`Self::min_cutoff`] first with the input held still to remove the jitter,
+then increase [`Self::beta`] while moving quickly until the lag is acceptable.
+
+The state is reset when the action returns to [`TriggerState::None`] or the value dimension changes.
+The first value after a reset passes through unchanged.
+
+[`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+
+# Examples
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+# let mut world = World::new();
+world.spawn((
+    Player,
+    actions!(Player[
+        (
+            Action::<Aim>::new(),
+            OneEuroFilter::new(1.0, 0.05),
+            Bindings::spawn(Axial::right_stick()),
+        ),
+    ]),
+));
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Aim;
+```
+*/
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, InputModifier)
+)]
+pub struct OneEuroFilter {
+    /// Minimum cutoff frequency in Hz.
+    ///
+    /// Lower values remove more jitter at low speeds, but increase the lag.
+    ///
+    /// By default set to 1.0.
+    pub min_cutoff: f32,
+
+    /// How much the cutoff frequency increases with speed.
+    ///
+    /// Higher values reduce the lag at high speeds.
+    ///
+    /// By default set to 0.0.
+    pub beta: f32,
+
+    /// Cutoff frequency in Hz for the speed estimation.
+    ///
+    /// By default set to 1.0.
+    pub derivative_cutoff: f32,
+
+    /// The type of time used to advance the filter.
+    pub time_kind: TimeKind,
+
+    state: Option<FilterState>,
+}
+
+impl OneEuroFilter {
+    #[must_use]
+    pub fn new(min_cutoff: f32, beta: f32) -> Self {
+        Self {
+            min_cutoff,
+            beta,
+            derivative_cutoff: 1.0,
+            time_kind: Default::default(),
+            state: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_derivative_cutoff(mut self, derivative_cutoff: f32) -> Self {
+        self.derivative_cutoff = derivative_cutoff;
+        self
+    }
+
+    #[must_use]
+    pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+}
+
+impl Default for OneEuroFilter {
+    fn default() -> Self {
+        Self::new(1.0, 0.0)
+    }
+}
+
+impl InputModifier for OneEuroFilter {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        if let ActionValue::Bool(value) = value {
+            let value = if value { 1.0 } else { 0.0 };
+            return self.transform(_actions, time, value.into());
+        }
+
+        let dim = value.dim();
+        let raw = value.as_axis3d();
+        let Some(state) = self.state.as_mut().filter(|state| state.dim == dim) else {
+            self.state = Some(FilterState {
+                dim,
+                value: raw,
+                derivative: Vec3::ZERO,
+            });
+            return value;
+        };
+
+        let delta_secs = time.delta_kind(self.time_kind).as_secs_f32();
+        if delta_secs <= 0.0 {
+            return ActionValue::Axis3D(state.value).convert(dim);
+        }
+
+        let derivative = (raw - state.value) / delta_secs;
+        state.derivative = state.derivative.lerp(
+            derivative,
+            smoothing_factor(self.derivative_cutoff, delta_secs),
+        );
+
+        let cutoff = self.min_cutoff + self.beta * state.derivative.length();
+        state.value = state.value.lerp(raw, smoothing_factor(cutoff, delta_secs));
+
+        ActionValue::Axis3D(state.value).convert(dim)
+    }
+
+    fn reset(&mut self) {
+        self.state = None;
+    }
+}
+
+/// Returns the interpolation factor of a low-pass filter for the given cutoff frequency.
+fn smoothing_factor(cutoff: f32, delta_secs: f32) -> f32 {
+    let time_constant = 1.0 / (TAU * cutoff);
+    1.0 / (1.0 + time_constant / delta_secs)
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Debug))]
+struct FilterState {
+    dim: ActionValueDim,
+    value: Vec3,
+    derivative: Vec3,
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn smoothing() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(100));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = OneEuroFilter::default();
+        assert_eq!(
+            modifier.transform(&actions, &time, 0.0.into()),
+            0.0.into(),
+            "should pass the first value"
+        );
+        let value = modifier.transform(&actions, &time, 1.0.into()).as_axis1d();
+        assert!((value - 0.3858).abs() < 1e-4);
+    }
+
+    #[test]
+    fn beta() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(100));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = OneEuroFilter::default();
+        let mut fast_modifier = OneEuroFilter::new(1.0, 1.0);
+        for value in [0.0, 1.0] {
+            modifier.transform(&actions, &time, value.into());
+            fast_modifier.transform(&actions, &time, value.into());
+        }
+        assert!(
+            fast_modifier.state.unwrap().value.x > modifier.state.unwrap().value.x,
+            "should follow faster with higher beta"
+        );
+    }
+
+    #[test]
+    fn reset() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(100));
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = OneEuroFilter::default();
+        modifier.transform(&actions, &time, 0.0.into());
+        modifier.reset();
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 1.0.into());
+
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::ZERO.into()),
+            Vec2::ZERO.into(),
+            "should reset on dimension change"
+        );
+    }
+}
@@ -0,0 +1,360 @@
+use alloc::vec::Vec;
+
+use bevy::{
+    math::curve::{Curve, EaseFunction, Interval},
+    prelude::*,
+};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/**
+Shapes input values with a custom curve.
+
+The curve maps the absolute input from `0.0` to `1.0` to the output magnitude.
+Inputs outside of this range are clamped before sampling. The sign is preserved.
+
+Applied either per axis or radially on the vector magnitude, see [`CurveMode`].
+Radial mode preserves the direction, which is usually preferred for sticks.
+
+Unlike [`ExponentialCurve`], the shape can be anything, such as an S-curve,
+a table of points authored by a designer or any Bevy [`Curve<f32>`] via [`Self::from_curve`].
+Can be reflected and serialized, so it can be stored with player settings.
+
+[`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+
+# Examples
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+# let mut world = World::new();
+world.spawn((
+    Player,
+    actions!(Player[
+        (
+            Action::<Aim>::new(),
+            // Slow near the center, then ramp up quickly.
+            ResponseCurve::points([
+                Vec2::ZERO,
+                Vec2::new(0.5, 0.2),
+                Vec2::new(0.8, 0.5),
+                Vec2::ONE,
+            ])
+            .with_mode(CurveMode::Radial),
+            Bindings::spawn(Axial::right_stick()),
+        ),
+    ]),
+));
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Aim;
+```
+*/
+#[derive(Component, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputModifier, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ResponseCurve {
+    /// Shape of the curve.
+    pub shape: CurveShape,
+
+    /// How the curve is applied to the value.
+    pub mode: CurveMode,
+}
+
+impl ResponseCurve {
+    #[must_use]
+    pub const fn new(shape: CurveShape) -> Self {
+        Self {
+            shape,
+            mode: CurveMode::PerAxis,
+        }
+    }
+
+    /// Creates a piecewise-linear curve from points.
+    ///
+    /// See [`CurveShape::Points`] for details.
+    #[must_use]
+    pub fn points(points: impl IntoIterator<Item = Vec2>) -> Self {
+        let mut points: Vec<_> = points.into_iter().collect();
+        points.sort_by(|a, b| a.x.total_cmp(&b.x));
+        Self::new(CurveShape::Points(points))
+    }
+
+    /// Creates a cubic Bézier curve.
+    ///
+    /// See [`CurveShape::CubicBezier`] for details.
+    #[must_use]
+    pub const fn cubic_bezier(p1: Vec2, p2: Vec2) -> Self {
+        Self::new(CurveShape::CubicBezier(p1, p2))
+    }
+
+    /// Creates a curve from an easing function.
+    #[must_use]
+    pub const fn ease(function: EaseFunction) -> Self {
+        Self::new(CurveShape::Ease(function))
+    }
+
+    /// Creates a piecewise-linear curve by sampling `curve` at evenly spaced points.
+    ///
+    /// The domain of the curve is mapped to the input range from `0.0` to `1.0`.
+    /// Sampling makes the curve serializable, use more samples for a closer match.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the curve domain is unbounded or `samples` is less than 2.
+    #[must_use]
+    pub fn from_curve(curve: &impl Curve<f32>, samples: usize) -> Self {
+        assert!(samples >= 2, "curve requires at least 2 samples");
+        let domain = curve.domain();
+        assert!(domain.is_bounded(), "curve domain should be bounded");
+
+        let points = (0..samples)
+            .map(|index| {
+                let x = index as f32 / (samples - 1) as f32;
+                let t = domain.start() + x * domain.length();
+                Vec2::new(x, curve.sample_clamped(t))
+            })
+            .collect();
+
+        Self::new(CurveShape::Points(points))
+    }
+
+    #[must_use]
+    pub const fn with_mode(mut self, mode: CurveMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+impl InputModifier for ResponseCurve {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        let apply = |value: f32| self.shape.sample_clamped(value.abs()).copysign(value);
+        let apply_radial = |value: Vec3| {
+            let length = value.length();
+            if length == 0.0 {
+                return value;
+            }
+            value * (self.shape.sample_clamped(length) / length)
+        };
+
+        match value {
+            ActionValue::Bool(value) => {
+                let value = if value { 1.0 } else { 0.0 };
+                apply(value).into()
+            }
+            ActionValue::Axis1D(value) => apply(value).into(),
+            ActionValue::Axis2D(value) => match self.mode {
+                CurveMode::PerAxis => value.map(apply).into(),
+                CurveMode::Radial => apply_radial(value.extend(0.0)).truncate().into(),
+            },
+            ActionValue::Axis3D(value) => match self.mode {
+                CurveMode::PerAxis => value.map(apply).into(),
+                CurveMode::Radial => apply_radial(value).into(),
+            },
+        }
+    }
+}
+
+/// Shape for [`ResponseCurve`].
+///
+/// Implements [`Curve<f32>`] over the unit interval, which can be used to visualize it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Debug, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum CurveShape {
+    /// Linear interpolation between points.
+    ///
+    /// Points don't need to be sorted, the closest points by X on both sides of the input are used.
+    /// X is the input and Y is the output. Inputs before the first point or after the last point
+    /// use the output of the nearest point. Without points, the input is returned unchanged.
+    Points(Vec<Vec2>),
+    /// Cubic Bézier curve from `(0, 0)` to `(1, 1)` with two control points, similar to CSS easing.
+    ///
+    /// X of both control points should be between `0.0` and `1.0`.
+    CubicBezier(Vec2, Vec2),
+    /// Bevy easing function.
+    Ease(EaseFunction),
+}
+
+impl Curve<f32> for CurveShape {
+    fn domain(&self) -> Interval {
+        Interval::UNIT
+    }
+
+    fn sample_unchecked(&self, t: f32) -> f32 {
+        match self {
+            CurveShape::Points(points) => sample_points(points, t),
+            CurveShape::CubicBezier(p1, p2) => sample_bezier(*p1, *p2, t),
+            CurveShape::Ease(function) => function.sample_unchecked(t),
+        }
+    }
+}
+
+fn sample_points(points: &[Vec2], x: f32) -> f32 {
+    // Points may come unsorted from deserialization or reflection,
+    // so find the closest points on both sides without relying on the order.
+    let mut lower: Option<Vec2> = None;
+    let mut upper: Option<Vec2> = None;
+    for &point in points {
+        if point.x <= x && lower.is_none_or(|lower| point.x >= lower.x) {
+            lower = Some(point);
+        }
+        if point.x >= x && upper.is_none_or(|upper| point.x < upper.x) {
+            upper = Some(point);
+        }
+    }
+
+    match (lower, upper) {
+        (Some(start), Some(end)) => {
+            let width = end.x - start.x;
+            if width <= 0.0 {
+                return end.y;
+            }
+            start.y.lerp(end.y, (x - start.x) / width)
+        }
+        (Some(point), None) | (None, Some(point)) => point.y,
+        (None, None) => x,
+    }
+}
+
+fn sample_bezier(p1: Vec2, p2: Vec2, x: f32) -> f32 {
+    let bezier = |t: f32, a: f32, b: f32| {
+        let u = 1.0 - t;
+        3.0 * u * u * t * a + 3.0 * u * t * t * b + t * t * t
+    };
+
+    // X is monotonic when control points are within the unit range, so find `t` using bisection.
+    let mut low = 0.0;
+    let mut high = 1.0;
+    for _ in 0..24 {
+        let t = (low + high) / 2.0;
+        if bezier(t, p1.x, p2.x) < x {
+            low = t;
+        } else {
+            high = t;
+        }
+    }
+
+    bezier((low + high) / 2.0, p1.y, p2.y)
+}
+
+/// How [`ResponseCurve`] or [`MouseAcceleration`] is applied to the value.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Default, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum CurveMode {
+    /// Applies the curve to each axis separately.
+    #[default]
+    PerAxis,
+    /// Applies the curve to the vector magnitude, preserving the direction.
+    Radial,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn points() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = ResponseCurve::points([Vec2::ONE, Vec2::ZERO, Vec2::new(0.5, 0.25)]);
+        assert_eq!(modifier.transform(&actions, &time, true.into()), 1.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, 0.25.into()),
+            0.125.into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, (-0.75).into()),
+            (-0.625).into()
+        );
+        assert_eq!(modifier.transform(&actions, &time, 2.0.into()), 1.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(0.5, -1.0).into()),
+            Vec2::new(0.25, -1.0).into()
+        );
+    }
+
+    #[test]
+    fn unsorted_points() {
+        let shape = CurveShape::Points([Vec2::ONE, Vec2::ZERO, Vec2::new(0.5, 0.25)].into());
+        assert_eq!(shape.sample_clamped(0.0), 0.0);
+        assert_eq!(shape.sample_clamped(0.25), 0.125);
+        assert_eq!(shape.sample_clamped(0.5), 0.25);
+        assert_eq!(shape.sample_clamped(0.75), 0.625);
+        assert_eq!(shape.sample_clamped(1.0), 1.0);
+    }
+
+    #[test]
+    fn radial() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = ResponseCurve::points([Vec2::ZERO, Vec2::new(0.5, 0.25), Vec2::ONE])
+            .with_mode(CurveMode::Radial);
+        let value = Vec2::new(0.3, 0.4);
+        let result = modifier
+            .transform(&actions, &time, value.into())
+            .as_axis2d();
+        assert!(result.abs_diff_eq(value.normalize() * 0.25, 1e-6));
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::ZERO.into()),
+            Vec2::ZERO.into()
+        );
+    }
+
+    #[test]
+    fn cubic_bezier() {
+        let curve = ResponseCurve::cubic_bezier(Vec2::new(0.6, 0.0), Vec2::new(0.4, 1.0));
+        assert!((curve.shape.sample_clamped(0.0)).abs() < 1e-4);
+        assert!((curve.shape.sample_clamped(0.5) - 0.5).abs() < 1e-4);
+        assert!((curve.shape.sample_clamped(1.0) - 1.0).abs() < 1e-4);
+        assert!(
+            curve.shape.sample_clamped(0.25) < 0.25,
+            "should be an S-curve"
+        );
+    }
+
+    #[test]
+    fn from_curve() {
+        let curve = ResponseCurve::from_curve(&EaseFunction::QuadraticIn, 11);
+        assert_eq!(curve.shape.sample_clamped(0.5), 0.25);
+
+        let ease = ResponseCurve::ease(EaseFunction::QuadraticIn);
+        assert_eq!(ease.shape.sample_clamped(0.5), 0.25);
+    }
+}
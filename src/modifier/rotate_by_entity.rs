@@ -0,0 +1,208 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/**
+Rotates the input by the rotation of an entity, such as a camera, and an additional yaw angle.
+
+Useful for camera-relative movement. [`ActionValue::Axis2D`] is treated as movement on the ground plane,
+with X pointing right and Y pointing forward, and is rotated only by the yaw of the entity,
+so looking up or down doesn't affect it. [`ActionValue::Axis3D`] is rotated by the full rotation,
+unless [`Self::project_to_ground`] is set.
+
+The rotation is read from [`GlobalTransform`] of [`Self::entity`] via [`InputWorld`] right before the evaluation.
+Since transforms are propagated in [`PostUpdate`], it's the rotation from the previous frame.
+If the entity is not set or doesn't have [`GlobalTransform`], only [`Self::angle`] is used.
+
+To read the rotation, [`GlobalTransform`] needs to be registered with [`InputWorldAppExt::add_input_component`].
+It's opt-in because the evaluation systems will read it on all entities,
+so they can't run in parallel with systems that mutate transforms.
+
+[`ActionValue::Bool`] and [`ActionValue::Axis1D`] are passed unchanged.
+
+# Examples
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+let mut app = App::new();
+app.add_plugins(EnhancedInputPlugin)
+    .add_input_component::<GlobalTransform>();
+
+let world = app.world_mut();
+let camera = world.spawn(Camera3d::default()).id();
+
+world.spawn((
+    Player,
+    actions!(Player[
+        (
+            Action::<Movement>::new(),
+            DeadZone::default(),
+            RotateByEntity::new(camera),
+            Bindings::spawn((
+                Cardinal::wasd_keys(),
+                Axial::left_stick(),
+            )),
+        ),
+    ]),
+));
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Movement;
+```
+*/
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, InputModifier)
+)]
+pub struct RotateByEntity {
+    /// Entity whose rotation is used.
+    pub entity: Option<Entity>,
+
+    /// Additional yaw in radians, counter-clockwise when viewed from above.
+    ///
+    /// By default set to 0.0.
+    pub angle: f32,
+
+    /// Whether to use only the yaw of the entity for [`ActionValue::Axis3D`].
+    ///
+    /// Keeps the movement on the ground plane regardless of the entity pitch and roll.
+    /// [`ActionValue::Axis2D`] always uses only the yaw.
+    ///
+    /// By default set to `false`.
+    pub project_to_ground: bool,
+
+    rotation: Quat,
+}
+
+impl RotateByEntity {
+    /// Creates a new instance that uses the rotation of the given entity.
+    #[must_use]
+    pub const fn new(entity: Entity) -> Self {
+        Self {
+            entity: Some(entity),
+            angle: 0.0,
+            project_to_ground: false,
+            rotation: Quat::IDENTITY,
+        }
+    }
+
+    /// Creates a new instance that rotates by a fixed yaw in radians.
+    #[must_use]
+    pub const fn from_angle(angle: f32) -> Self {
+        Self {
+            entity: None,
+            angle,
+            project_to_ground: false,
+            rotation: Quat::IDENTITY,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_angle(mut self, angle: f32) -> Self {
+        self.angle = angle;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_project_to_ground(mut self, project_to_ground: bool) -> Self {
+        self.project_to_ground = project_to_ground;
+        self
+    }
+
+    /// Returns the rotation of the entity from the last update.
+    #[must_use]
+    pub fn rotation(&self) -> Quat {
+        self.rotation
+    }
+
+    fn yaw_rotation(&self) -> Quat {
+        // Project the forward direction onto the ground plane instead of extracting
+        // the Euler yaw, which is unstable when looking straight up or down.
+        let forward = self.rotation * Vec3::NEG_Z;
+        let mut direction = Vec2::new(forward.x, forward.z);
+        if direction.length_squared() < MIN_PROJECTION_SQUARED {
+            // The top of the view points forward when looking down and backward when looking up.
+            let up = self.rotation * Vec3::Y * -forward.y.signum();
+            direction = Vec2::new(up.x, up.z);
+        }
+
+        let yaw = ops::atan2(-direction.x, -direction.y);
+        Quat::from_rotation_y(yaw + self.angle)
+    }
+}
+
+/// Squared length of the projected forward direction below which the up direction is used instead.
+const MIN_PROJECTION_SQUARED: f32 = 1e-6;
+
+impl Default for RotateByEntity {
+    fn default() -> Self {
+        Self::from_angle(0.0)
+    }
+}
+
+impl InputModifier for RotateByEntity {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        match value {
+            ActionValue::Bool(_) | ActionValue::Axis1D(_) => value,
+            ActionValue::Axis2D(value) => {
+                let rotated = self.yaw_rotation() * Vec3::new(value.x, 0.0, -value.y);
+                Vec2::new(rotated.x, -rotated.z).into()
+            }
+            ActionValue::Axis3D(value) => {
+                let rotation = if self.project_to_ground {
+                    self.yaw_rotation()
+                } else {
+                    self.rotation * Quat::from_rotation_y(self.angle)
+                };
+                (rotation * value).into()
+            }
+        }
+    }
+
+    fn read_world(&mut self, world: &InputWorld) {
+        self.rotation = self
+            .entity
+            .and_then(|entity| world.get::<GlobalTransform>(entity))
+            .map(GlobalTransform::rotation)
+            .unwrap_or_default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f32::consts::FRAC_PI_2;
+
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn angle() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = RotateByEntity::from_angle(FRAC_PI_2);
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 1.0.into());
+        let value = modifier
+            .transform(&actions, &time, Vec2::Y.into())
+            .as_axis2d();
+        assert!(value.abs_diff_eq(Vec2::NEG_X, 1e-6));
+
+        let value = modifier
+            .transform(&actions, &time, Vec3::NEG_Z.into())
+            .as_axis3d();
+        assert!(value.abs_diff_eq(Vec3::NEG_X, 1e-6));
+    }
+}
@@ -6,7 +6,11 @@ use crate::prelude::*;
 ///
 /// [`ActionValue::Bool`] will be converted into [`ActionValue::Axis1D`].
 #[derive(Component, Debug, Clone, Copy)]
-#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputModifier)
+)]
 pub struct Scale {
     /// The factor applied to the input value.
     ///
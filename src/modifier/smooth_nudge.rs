@@ -11,7 +11,7 @@ use crate::prelude::*;
 #[cfg_attr(
     feature = "reflect",
     derive(Reflect),
-    reflect(Clone, Component, Debug, Default)
+    reflect(Clone, Component, Debug, Default, InputModifier)
 )]
 pub struct SmoothNudge {
     /// Multiplier for delta time, determines the rate of smoothing.
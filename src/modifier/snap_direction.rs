@@ -0,0 +1,117 @@
+use core::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Snaps the direction of the value to the nearest of evenly spaced directions.
+///
+/// The first direction points along the positive X axis, and the rest follow counter-clockwise.
+/// The magnitude is preserved, so magnitude-based conditions like [`Flick`] keep working.
+/// Only X and Y axes are affected.
+///
+/// Matches the layout of [`Sector`].
+///
+/// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, InputModifier)
+)]
+pub struct SnapDirection {
+    /// Number of directions to snap to.
+    pub directions: u32,
+}
+
+impl SnapDirection {
+    #[must_use]
+    pub const fn new(directions: u32) -> Self {
+        Self { directions }
+    }
+
+    /// Returns [`Self`] with 4 directions, like a d-pad without diagonals.
+    #[must_use]
+    pub const fn four_way() -> Self {
+        Self::new(4)
+    }
+
+    /// Returns [`Self`] with 8 directions, like a d-pad with diagonals.
+    #[must_use]
+    pub const fn eight_way() -> Self {
+        Self::new(8)
+    }
+}
+
+impl InputModifier for SnapDirection {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        let dim = value.dim();
+        if dim == ActionValueDim::Bool {
+            return value.convert(ActionValueDim::Axis1D);
+        }
+
+        let value = value.as_axis3d();
+        let xy = value.xy();
+        if xy == Vec2::ZERO || self.directions == 0 {
+            return ActionValue::Axis3D(value).convert(dim);
+        }
+
+        let step = TAU / self.directions as f32;
+        let angle = ops::round(xy.to_angle() / step) * step;
+        let snapped = Vec2::from_angle(angle) * xy.length();
+
+        ActionValue::Axis3D(snapped.extend(value.z)).convert(dim)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn eight_way() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = SnapDirection::eight_way();
+        let value = modifier
+            .transform(&actions, &time, Vec2::new(0.9, 0.2).into())
+            .as_axis2d();
+        assert!(value.abs_diff_eq(Vec2::X * Vec2::new(0.9, 0.2).length(), 1e-6));
+
+        let value = modifier
+            .transform(&actions, &time, Vec2::new(0.5, 0.6).into())
+            .as_axis2d();
+        let expected = Vec2::ONE.normalize() * Vec2::new(0.5, 0.6).length();
+        assert!(value.abs_diff_eq(expected, 1e-6));
+
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::ZERO.into()),
+            Vec2::ZERO.into()
+        );
+    }
+
+    #[test]
+    fn four_way() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = SnapDirection::four_way();
+        let value = modifier
+            .transform(&actions, &time, Vec2::new(-0.3, -0.8).into())
+            .as_axis2d();
+        assert!(value.abs_diff_eq(Vec2::NEG_Y * Vec2::new(-0.3, -0.8).length(), 1e-6));
+
+        assert_eq!(modifier.transform(&actions, &time, true.into()), 1.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, (-0.5).into()),
+            (-0.5).into()
+        );
+    }
+}
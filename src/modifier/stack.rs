@@ -0,0 +1,109 @@
+use alloc::vec::Vec;
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/**
+Ordered list of modifiers of any types applied as a single modifier.
+
+Allows applying multiple modifiers of the same type, such as "scale, curve, then scale again".
+Modifiers are applied in their order at the position of the stack component.
+
+The stack is registered by the plugin. Modifiers inside it don't need to be registered,
+but need [`ReflectInputModifier`] to be serialized. See [`ErasedModifier`] for details.
+
+# Examples
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+let mut world = World::new();
+world.spawn((
+    Player,
+    actions!(Player[
+        (
+            Action::<Aim>::new(),
+            ModifierStack::default()
+                .with(Scale::splat(0.5))
+                .with(ExponentialCurve::splat(2.0))
+                .with(Scale::splat(4.0)),
+            Bindings::spawn(Axial::right_stick()),
+        ),
+    ]),
+));
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Aim;
+```
+*/
+#[derive(Component, Debug, Clone, Default, Deref, DerefMut)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, InputModifier)
+)]
+pub struct ModifierStack(pub Vec<ErasedModifier>);
+
+impl ModifierStack {
+    /// Adds a modifier to the end of the stack.
+    #[must_use]
+    pub fn with(mut self, modifier: impl Into<ErasedModifier>) -> Self {
+        self.0.push(modifier.into());
+        self
+    }
+}
+
+impl InputModifier for ModifierStack {
+    fn transform(
+        &mut self,
+        actions: &ActionsQuery,
+        time: &ContextTime,
+        mut value: ActionValue,
+    ) -> ActionValue {
+        for modifier in &mut self.0 {
+            value = modifier.transform(actions, time, value);
+        }
+        value
+    }
+
+    fn reset(&mut self) {
+        for modifier in &mut self.0 {
+            modifier.reset();
+        }
+    }
+
+    fn read_world(&mut self, world: &InputWorld) {
+        for modifier in &mut self.0 {
+            modifier.read_world(world);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn order() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world).unwrap();
+
+        let mut modifier = ModifierStack::default()
+            .with(Scale::splat(2.0))
+            .with(Clamp::splat(-1.0, 1.0))
+            .with(Scale::splat(3.0));
+        assert_eq!(modifier.transform(&actions, &time, 0.25.into()), 1.5.into());
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 3.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, (-1.0).into()),
+            (-3.0).into()
+        );
+    }
+}
@@ -167,6 +167,7 @@ struct InputSettings {
 pub mod axial;
 pub mod bidirectional;
 pub mod cardinal;
+pub mod navigation;
 pub mod ordinal;
 pub mod spatial;
 
@@ -0,0 +1,88 @@
+use bevy::{
+    ecs::spawn::SpawnableList,
+    prelude::*,
+    ptr::{MovingPtr, move_as_ptr},
+};
+
+use crate::prelude::*;
+
+/// A preset to map arrow keys, D-pad and left stick as 2-dimensional input for menu navigation.
+///
+/// Combine with [`StepRepeat`] on the action to turn the input into discrete steps.
+#[derive(Debug, Clone, Copy)]
+pub struct Navigation<K, D, S> {
+    pub keys: K,
+    pub dpad: D,
+    pub stick: S,
+}
+
+impl<K: WithBundle<T>, D: WithBundle<T>, S: WithBundle<T>, T: Clone> WithBundle<T>
+    for Navigation<K, D, S>
+{
+    type Output = Navigation<K::Output, D::Output, S::Output>;
+
+    fn with(self, bundle: T) -> Self::Output {
+        Navigation {
+            keys: self.keys.with(bundle.clone()),
+            dpad: self.dpad.with(bundle.clone()),
+            stick: self.stick.with(bundle),
+        }
+    }
+}
+
+impl
+    Navigation<
+        Cardinal<Binding, Binding, Binding, Binding>,
+        Cardinal<Binding, Binding, Binding, Binding>,
+        Axial<Binding, Binding>,
+    >
+{
+    /// Maps arrow keys, D-pad and left stick.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            keys: Cardinal::arrows(),
+            dpad: Cardinal::dpad(),
+            stick: Axial::left_stick(),
+        }
+    }
+}
+
+impl Default
+    for Navigation<
+        Cardinal<Binding, Binding, Binding, Binding>,
+        Cardinal<Binding, Binding, Binding, Binding>,
+        Axial<Binding, Binding>,
+    >
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, D, S> SpawnableList<BindingOf> for Navigation<K, D, S>
+where
+    K: SpawnableList<BindingOf>,
+    D: SpawnableList<BindingOf>,
+    S: SpawnableList<BindingOf>,
+{
+    fn spawn(this: MovingPtr<'_, Self>, world: &mut World, entity: Entity) {
+        let navigation = this.read();
+
+        let keys = navigation.keys;
+        move_as_ptr!(keys);
+        SpawnableList::spawn(keys, world, entity);
+
+        let dpad = navigation.dpad;
+        move_as_ptr!(dpad);
+        SpawnableList::spawn(dpad, world, entity);
+
+        let stick = navigation.stick;
+        move_as_ptr!(stick);
+        SpawnableList::spawn(stick, world, entity);
+    }
+
+    fn size_hint(&self) -> usize {
+        self.keys.size_hint() + self.dpad.size_hint() + self.stick.size_hint()
+    }
+}
@@ -0,0 +1,74 @@
+use alloc::{boxed::Box, format};
+use core::any::TypeId;
+
+use bevy::{
+    prelude::*,
+    reflect::{
+        ReflectFromReflect, TypeData, TypeRegistry,
+        serde::{ReflectDeserializer, ReflectSerializer},
+    },
+};
+use serde::{
+    Deserializer, Serialize, Serializer,
+    de::{self, DeserializeSeed},
+    ser,
+};
+
+/// Serializes the value as a map from its type path to the reflected value.
+///
+/// `as_reflect` should return the value using the type data `T` registered for `type_id`.
+pub(crate) fn serialize<'a, T: TypeData, S: Serializer>(
+    serializer: S,
+    registry: &TypeRegistry,
+    type_id: TypeId,
+    type_name: &str,
+    as_reflect: impl FnOnce(&T) -> Option<&'a dyn Reflect>,
+) -> Result<S::Ok, S::Error> {
+    let reflect = registry
+        .get_type_data::<T>(type_id)
+        .and_then(as_reflect)
+        .ok_or_else(|| {
+            ser::Error::custom(format!(
+                "`{type_name}` should be registered with `{}`",
+                ShortName::of::<T>()
+            ))
+        })?;
+
+    ReflectSerializer::new(reflect.as_partial_reflect(), registry).serialize(serializer)
+}
+
+/// Deserializes a value serialized with [`serialize`].
+///
+/// `from_reflect` should convert the deserialized value using the type data `T` registered for its type.
+pub(crate) fn deserialize<'de, T: TypeData, R, D: Deserializer<'de>>(
+    deserializer: D,
+    registry: &TypeRegistry,
+    from_reflect: impl FnOnce(&T, Box<dyn Reflect>) -> Option<R>,
+) -> Result<R, D::Error> {
+    let reflect = ReflectDeserializer::new(registry).deserialize(deserializer)?;
+
+    let type_path = reflect.reflect_type_path();
+    let registration = reflect
+        .get_represented_type_info()
+        .and_then(|info| registry.get(info.type_id()))
+        .ok_or_else(|| de::Error::custom(format!("`{type_path}` should be registered")))?;
+
+    let reflect = registration
+        .data::<ReflectFromReflect>()
+        .and_then(|from_reflect| from_reflect.from_reflect(&*reflect))
+        .ok_or_else(|| {
+            de::Error::custom(format!(
+                "`{type_path}` should be registered with `ReflectFromReflect`"
+            ))
+        })?;
+
+    registration
+        .data::<T>()
+        .and_then(|type_data| from_reflect(type_data, reflect))
+        .ok_or_else(|| {
+            de::Error::custom(format!(
+                "`{type_path}` should be registered with `{}`",
+                ShortName::of::<T>()
+            ))
+        })
+}
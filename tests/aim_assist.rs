@@ -0,0 +1,123 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn slowdown() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_resource::<AimTargets>()
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                Bindings::spawn((
+                    Cardinal::arrows(),
+                    Axial::right_stick().with(AimSlowdown::new(0.5)),
+                )),
+            )]
+        ),
+    ));
+
+    app.world_mut()
+        .resource_mut::<AimTargets>()
+        .push(AimTarget::new(Vec2::ZERO, 10.0));
+
+    app.update();
+
+    app.world_mut()
+        .get_mut::<Gamepad>(gamepad)
+        .unwrap()
+        .analog_mut()
+        .set(GamepadAxis::RightStickX, 0.5);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&Action<Test>>();
+    let value = **actions.single(app.world()).unwrap();
+    assert_eq!(value, Vec2::new(0.25, 0.0));
+
+    app.world_mut().resource_mut::<AimTargets>().clear();
+
+    app.update();
+
+    let value = **actions.single(app.world()).unwrap();
+    assert_eq!(value, Vec2::new(0.5, 0.0));
+
+    app.world_mut()
+        .get_mut::<Gamepad>(gamepad)
+        .unwrap()
+        .analog_mut()
+        .set(GamepadAxis::RightStickX, 0.0);
+    app.world_mut()
+        .resource_mut::<AimTargets>()
+        .push(AimTarget::new(Vec2::ZERO, 10.0));
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::ArrowRight);
+
+    app.update();
+
+    let value = **actions.single(app.world()).unwrap();
+    assert_eq!(value, Vec2::X, "only gamepad bindings should be affected");
+}
+
+#[test]
+fn magnetism() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_resource::<AimTargets>()
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                Bindings::spawn(
+                    Axial::right_stick().with(
+                        AimMagnetism::new(0.5)
+                            .with_zone(AimAssistZone::new(0.2).with_distance(10.0, 30.0)),
+                    )
+                ),
+            )]
+        ),
+    ));
+
+    app.world_mut().resource_mut::<AimTargets>().extend([
+        AimTarget::new(Vec2::new(0.0, 0.1), 10.0),
+        AimTarget::new(Vec2::new(0.0, -0.1), 20.0),
+    ]);
+
+    app.update();
+
+    app.world_mut()
+        .get_mut::<Gamepad>(gamepad)
+        .unwrap()
+        .analog_mut()
+        .set(GamepadAxis::RightStickX, 0.4);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&Action<Test>>();
+    let value = **actions.single(app.world()).unwrap();
+    assert_eq!(
+        value,
+        Vec2::new(0.4, 0.1),
+        "should pull toward the target with the highest weight"
+    );
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Test;
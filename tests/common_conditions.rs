@@ -0,0 +1,248 @@
+use bevy::{input::InputPlugin, prelude::*, time::TimeUpdateStrategy};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn events() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<Runs>()
+        .add_input_context::<TestContext>()
+        .add_systems(
+            Update,
+            (
+                count_started.run_if(action_started::<Test>),
+                count_fired.run_if(action_fired::<Test>),
+                count_completed.run_if(action_completed::<Test>),
+                count_active.run_if(action_active::<Test>),
+            ),
+        )
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), bindings![Test::KEY])]),
+    ));
+
+    app.update();
+
+    assert_eq!(*app.world().resource::<Runs>(), Runs::default());
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+
+    app.update();
+    app.update();
+
+    let runs = *app.world().resource::<Runs>();
+    assert_eq!(runs.started, 1);
+    assert_eq!(runs.fired, 2);
+    assert_eq!(runs.completed, 0);
+    assert_eq!(runs.active, 2);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(Test::KEY);
+
+    app.update();
+
+    let runs = *app.world().resource::<Runs>();
+    assert_eq!(runs.started, 1);
+    assert_eq!(runs.fired, 2);
+    assert_eq!(runs.completed, 1);
+    assert_eq!(runs.active, 2);
+}
+
+#[test]
+fn per_context() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<Runs>()
+        .add_input_context::<TestContext>()
+        .add_input_context::<OtherContext>()
+        .add_systems(
+            Update,
+            (
+                count_started.run_if(action_started_in::<TestContext, Test>),
+                count_active.run_if(action_active_in::<OtherContext, Test>),
+            ),
+        )
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), bindings![Test::KEY])]),
+    ));
+    app.world_mut().spawn((
+        OtherContext,
+        actions!(OtherContext[(Action::<Test>::new(), bindings![KeyCode::KeyB])]),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+
+    app.update();
+
+    let runs = *app.world().resource::<Runs>();
+    assert_eq!(runs.started, 1);
+    assert_eq!(
+        runs.active, 0,
+        "action from the other context shouldn't be active"
+    );
+}
+
+#[test]
+fn value_above() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, EnhancedInputPlugin))
+        .init_resource::<Runs>()
+        .add_input_context::<TestContext>()
+        .add_systems(
+            Update,
+            (
+                count_active.run_if(action_value_above::<Axis>(0.5)),
+                count_fired.run_if(action_value_above_in::<OtherContext, Axis>(0.5)),
+            ),
+        )
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((TestContext, actions!(TestContext[Action::<Axis>::new()])))
+        .id();
+
+    app.world_mut()
+        .entity_mut(context)
+        .mock_once::<TestContext, Axis>(TriggerState::Fired, 0.5)
+        .unwrap();
+
+    app.update();
+
+    assert_eq!(app.world().resource::<Runs>().active, 0);
+
+    app.world_mut()
+        .entity_mut(context)
+        .mock_once::<TestContext, Axis>(TriggerState::Fired, -0.7)
+        .unwrap();
+
+    app.update();
+
+    let runs = *app.world().resource::<Runs>();
+    assert_eq!(runs.active, 1);
+    assert_eq!(runs.fired, 0, "action belongs to a different context");
+}
+
+#[test]
+fn fixed_timestep() {
+    let time_step = Time::<Fixed>::default().timestep() / 2;
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(time_step))
+        .init_resource::<Runs>()
+        .add_input_context_to::<FixedPreUpdate, TestContext>()
+        .add_systems(
+            FixedUpdate,
+            (
+                count_started.run_if(action_started::<Test>),
+                count_fired.run_if(action_fired::<Test>),
+            ),
+        )
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), bindings![Test::KEY])]),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+
+    for _ in 0..6 {
+        app.update();
+    }
+
+    let runs = *app.world().resource::<Runs>();
+    assert_eq!(runs.started, 1, "should start only on the first fixed run");
+    assert_eq!(runs.fired, 2, "should fire once per fixed run");
+}
+
+#[test]
+fn multiple_fixed_runs() {
+    let time_step = Time::<Fixed>::default().timestep() * 2;
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(time_step))
+        .init_resource::<Runs>()
+        .add_input_context_to::<FixedPreUpdate, TestContext>()
+        .add_systems(
+            FixedUpdate,
+            (
+                count_started.run_if(action_started::<Test>),
+                count_fired.run_if(action_fired::<Test>),
+            ),
+        )
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), bindings![Test::KEY])]),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+
+    app.update();
+    app.update();
+
+    let runs = *app.world().resource::<Runs>();
+    assert_eq!(runs.started, 1, "should start only on the first fixed run");
+    assert_eq!(runs.fired, 2, "should fire on each fixed run");
+}
+
+fn count_started(mut runs: ResMut<Runs>) {
+    runs.started += 1;
+}
+
+fn count_fired(mut runs: ResMut<Runs>) {
+    runs.fired += 1;
+}
+
+fn count_completed(mut runs: ResMut<Runs>) {
+    runs.completed += 1;
+}
+
+fn count_active(mut runs: ResMut<Runs>) {
+    runs.active += 1;
+}
+
+#[derive(Resource, Default, Debug, PartialEq, Eq, Clone, Copy)]
+struct Runs {
+    started: usize,
+    fired: usize,
+    completed: usize,
+    active: usize,
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(Component)]
+struct OtherContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Test;
+
+impl Test {
+    const KEY: KeyCode = KeyCode::KeyA;
+}
+
+#[derive(InputAction)]
+#[action_output(f32)]
+struct Axis;
@@ -0,0 +1,234 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn evaluated_first() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app.world_mut().spawn(TestContext).id();
+    let test = app
+        .world_mut()
+        .spawn((
+            ActionOf::<TestContext>::new(context),
+            Action::<Test>::new(),
+            bindings![Test::KEY],
+        ))
+        .id();
+    let member = app
+        .world_mut()
+        .spawn((
+            ActionOf::<TestContext>::new(context),
+            Action::<Member>::new(),
+            bindings![Member::KEY],
+        ))
+        .id();
+    app.world_mut()
+        .entity_mut(test)
+        .insert(Chord::single(member));
+
+    app.update();
+
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.press(Test::KEY);
+    keys.press(Member::KEY);
+
+    app.update();
+
+    let state = *app.world().get::<TriggerState>(test).unwrap();
+    assert_eq!(
+        state,
+        TriggerState::Fired,
+        "referenced action should be evaluated first even if spawned later"
+    );
+
+    let actions = app.world().get::<Actions<TestContext>>(context).unwrap();
+    assert_eq!(actions.collection(), &[member, test]);
+}
+
+#[test]
+fn mutated_condition() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app.world_mut().spawn(TestContext).id();
+    let test = app
+        .world_mut()
+        .spawn((
+            ActionOf::<TestContext>::new(context),
+            Action::<Test>::new(),
+            Chord::new(Vec::new()),
+            bindings![Test::KEY],
+        ))
+        .id();
+    let member = app
+        .world_mut()
+        .spawn((
+            ActionOf::<TestContext>::new(context),
+            Action::<Member>::new(),
+            bindings![Member::KEY],
+        ))
+        .id();
+
+    app.update();
+
+    let actions = app.world().get::<Actions<TestContext>>(context).unwrap();
+    assert_eq!(actions.collection(), &[test, member]);
+
+    let mut chord = app.world_mut().get_mut::<Chord>(test).unwrap();
+    chord.actions.push(member);
+
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.press(Test::KEY);
+    keys.press(Member::KEY);
+
+    app.update();
+
+    let state = *app.world().get::<TriggerState>(test).unwrap();
+    assert_eq!(
+        state,
+        TriggerState::Fired,
+        "actions should be sorted again after changing referenced actions"
+    );
+
+    let actions = app.world().get::<Actions<TestContext>>(context).unwrap();
+    assert_eq!(actions.collection(), &[member, test]);
+}
+
+#[test]
+fn lower_priority() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .add_input_context::<OtherContext>()
+        .finish();
+
+    let other = app.world_mut().spawn(OtherContext).id();
+    let member = app
+        .world_mut()
+        .spawn((
+            ActionOf::<OtherContext>::new(other),
+            Action::<Member>::new(),
+            bindings![Member::KEY],
+        ))
+        .id();
+
+    let context = app
+        .world_mut()
+        .spawn((TestContext, ContextPriority::<TestContext>::new(1)))
+        .id();
+    let test = app
+        .world_mut()
+        .spawn((
+            ActionOf::<TestContext>::new(context),
+            Action::<Test>::new(),
+            Chord::single(member),
+            bindings![Test::KEY],
+        ))
+        .id();
+
+    app.update();
+
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.press(Test::KEY);
+    keys.press(Member::KEY);
+
+    app.update();
+
+    let state = *app.world().get::<TriggerState>(test).unwrap();
+    assert_eq!(
+        state,
+        TriggerState::None,
+        "referenced action from a context evaluated later should use the previous state"
+    );
+
+    app.update();
+
+    let state = *app.world().get::<TriggerState>(test).unwrap();
+    assert_eq!(state, TriggerState::Fired);
+}
+
+#[test]
+fn modifier_keys_first() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app.world_mut().spawn(TestContext).id();
+    let member = app
+        .world_mut()
+        .spawn((
+            ActionOf::<TestContext>::new(context),
+            Action::<Member>::new(),
+            bindings![Member::KEY],
+        ))
+        .id();
+    let test = app
+        .world_mut()
+        .spawn((
+            ActionOf::<TestContext>::new(context),
+            Action::<Test>::new(),
+            Chord::single(member),
+            bindings![Binding::Keyboard {
+                key: Test::KEY,
+                mod_keys: ModKeys::CONTROL
+            }],
+        ))
+        .id();
+
+    app.update();
+
+    let actions = app.world().get::<Actions<TestContext>>(context).unwrap();
+    assert_eq!(
+        actions.collection(),
+        &[test, member],
+        "actions with more modifier keys should be evaluated first"
+    );
+
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.press(KeyCode::ControlLeft);
+    keys.press(Test::KEY);
+    keys.press(Member::KEY);
+
+    app.update();
+
+    let state = *app.world().get::<TriggerState>(test).unwrap();
+    assert_eq!(
+        state,
+        TriggerState::None,
+        "referenced action with fewer modifier keys should use the previous state"
+    );
+
+    app.update();
+
+    let state = *app.world().get::<TriggerState>(test).unwrap();
+    assert_eq!(state, TriggerState::Fired);
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(Component)]
+struct OtherContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Test;
+
+impl Test {
+    const KEY: KeyCode = KeyCode::KeyA;
+}
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Member;
+
+impl Member {
+    const KEY: KeyCode = KeyCode::KeyB;
+}
@@ -142,6 +142,46 @@ fn axis3d() {
     assert_eq!(*action, Vec3::ZERO);
 }
 
+#[test]
+fn integer2d() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Integer2D>::new(),
+                Bindings::spawn(Cardinal::wasd_keys())
+            )]
+        ),
+    ));
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyW);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&Action<Integer2D>>();
+
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(*action, IVec2::Y);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(KeyCode::KeyW);
+
+    app.update();
+
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(*action, IVec2::ZERO);
+}
+
 #[derive(Component)]
 struct TestContext;
 
@@ -176,3 +216,7 @@ struct Axis3D;
 impl Axis3D {
     const KEY: KeyCode = KeyCode::KeyD;
 }
+
+#[derive(InputAction)]
+#[action_output(IVec2)]
+struct Integer2D;
@@ -0,0 +1,64 @@
+use core::time::Duration;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn mocked() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(TestContext[(Action::<Test>::new(), ActionHistory::new(3))]),
+        ))
+        .id();
+
+    app.update();
+
+    for value in [0.5, 1.0, 0.25] {
+        app.world_mut()
+            .entity_mut(context)
+            .mock_once::<TestContext, Test>(TriggerState::Fired, value)
+            .unwrap();
+
+        app.update();
+    }
+
+    let mut histories = app.world_mut().query::<&ActionHistory>();
+    let history = histories.single(app.world()).unwrap();
+    assert_eq!(history.len(), 3);
+
+    let latest = *history.get(0).unwrap();
+    assert_eq!(latest.value, 0.25.into());
+    assert_eq!(latest.state, TriggerState::Fired);
+    assert_eq!(history.get(2).unwrap().value, 0.5.into());
+    assert_eq!(history.peak(0.15), Some(1.0.into()));
+
+    let secs_since_start = history.secs_since_start().unwrap();
+    assert!((secs_since_start - 0.2).abs() < 1e-4);
+
+    let earliest = history.get(2).unwrap();
+    assert!(((latest.timestamp - earliest.timestamp).as_secs_f32() - 0.2).abs() < 1e-4);
+
+    app.update();
+
+    let history = histories.single(app.world()).unwrap();
+    assert_eq!(history.get(0).unwrap().state, TriggerState::None);
+    assert_eq!(history.get(0).unwrap().value, 0.0.into());
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(f32)]
+struct Test;
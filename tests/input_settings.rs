@@ -0,0 +1,144 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn scale_and_negate() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_resource::<InputSettings>()
+        .add_input_context::<TestContext>()
+        .insert_resource(
+            InputSettings::default()
+                .with_scalar(SENSITIVITY, 2.0)
+                .with_bool(INVERT_Y, true),
+        )
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                ScaleBySetting::new(SENSITIVITY),
+                NegateBySetting::new(Negate::y(), INVERT_Y),
+                Bindings::spawn(Cardinal::arrows()),
+            )]
+        ),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::ArrowUp);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&Action<Test>>();
+    let value = **actions.single(app.world()).unwrap();
+    assert_eq!(value, Vec2::new(0.0, -2.0));
+
+    let mut settings = app.world_mut().resource_mut::<InputSettings>();
+    settings.set_scalar(SENSITIVITY, 0.5);
+    settings.set_bool(INVERT_Y, false);
+
+    app.update();
+
+    let value = **actions.single(app.world()).unwrap();
+    assert_eq!(
+        value,
+        Vec2::new(0.0, 0.5),
+        "changes should apply immediately"
+    );
+}
+
+#[test]
+fn missing() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_resource::<InputSettings>()
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                Scale::splat(3.0),
+                ScaleBySetting::new(SENSITIVITY),
+                NegateBySetting::new(Negate::y(), INVERT_Y),
+                Bindings::spawn(Cardinal::arrows()),
+            )]
+        ),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::ArrowUp);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&Action<Test>>();
+    let value = **actions.single(app.world()).unwrap();
+    assert_eq!(
+        value,
+        Vec2::new(0.0, -3.0),
+        "missing settings should be ignored"
+    );
+}
+
+#[test]
+fn dead_zone() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_resource::<InputSettings>()
+        .add_input_context::<TestContext>()
+        .insert_resource(InputSettings::default().with_scalar(DEAD_ZONE, 0.5))
+        .finish();
+
+    let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                DeadZoneBySetting::new(DeadZone::default(), DEAD_ZONE),
+                Bindings::spawn(Axial::left_stick()),
+            )]
+        ),
+    ));
+
+    app.update();
+
+    app.world_mut()
+        .get_mut::<Gamepad>(gamepad)
+        .unwrap()
+        .analog_mut()
+        .set(GamepadAxis::LeftStickX, 0.4);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&Action<Test>>();
+    let value = **actions.single(app.world()).unwrap();
+    assert_eq!(value, Vec2::ZERO);
+
+    app.world_mut()
+        .resource_mut::<InputSettings>()
+        .set_scalar(DEAD_ZONE, 0.2);
+
+    app.update();
+
+    let value = **actions.single(app.world()).unwrap();
+    assert_eq!(value, Vec2::new(0.25, 0.0));
+}
+
+const SENSITIVITY: &str = "sensitivity";
+const INVERT_Y: &str = "invert_y";
+const DEAD_ZONE: &str = "dead_zone";
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Test;
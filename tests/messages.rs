@@ -0,0 +1,180 @@
+use bevy::{input::InputPlugin, prelude::*, time::TimeUpdateStrategy};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn written() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_message::<ActionEventMessage>()
+        .init_resource::<ReceivedMessages>()
+        .add_input_context::<TestContext>()
+        .add_systems(Update, receive)
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(TestContext[(Action::<Test>::new(), bindings![Test::KEY])]),
+        ))
+        .id();
+
+    app.update();
+
+    assert!(app.world().resource::<ReceivedMessages>().is_empty());
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+
+    app.update();
+
+    let mut actions = app
+        .world_mut()
+        .query_filtered::<Entity, With<Action<Test>>>();
+    let action = actions.single(app.world()).unwrap();
+
+    let messages = app
+        .world_mut()
+        .resource_mut::<ReceivedMessages>()
+        .split_off(0);
+    assert_eq!(messages.len(), 1);
+    let message = messages[0];
+    assert_eq!(message.context, context);
+    assert_eq!(message.action, action);
+    assert_eq!(message.events, ActionEvents::START | ActionEvents::FIRE);
+    assert_eq!(message.state, TriggerState::Fired);
+    assert_eq!(message.value, true.into());
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(Test::KEY);
+
+    app.update();
+
+    let messages = app
+        .world_mut()
+        .resource_mut::<ReceivedMessages>()
+        .split_off(0);
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].events, ActionEvents::COMPLETE);
+    assert_eq!(messages[0].state, TriggerState::None);
+}
+
+#[test]
+fn removal() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_message::<ActionEventMessage>()
+        .init_resource::<ReceivedMessages>()
+        .add_input_context::<TestContext>()
+        .add_systems(Update, receive)
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(TestContext[(Action::<Test>::new(), bindings![Test::KEY])]),
+        ))
+        .id();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+
+    app.update();
+
+    app.world_mut().resource_mut::<ReceivedMessages>().clear();
+    app.world_mut()
+        .entity_mut(context)
+        .despawn_related::<Actions<TestContext>>();
+
+    app.update();
+
+    let messages = app
+        .world_mut()
+        .resource_mut::<ReceivedMessages>()
+        .split_off(0);
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].events, ActionEvents::COMPLETE);
+}
+
+#[test]
+fn fixed_timestep() {
+    let time_step = Time::<Fixed>::default().timestep() / 2;
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(time_step))
+        .add_message::<ActionEventMessage>()
+        .init_resource::<ReceivedMessages>()
+        .add_input_context_to::<FixedPreUpdate, TestContext>()
+        .add_systems(Update, receive)
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), bindings![Test::KEY])]),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+
+    for _ in 0..6 {
+        app.update();
+    }
+
+    let messages = app.world().resource::<ReceivedMessages>();
+    assert_eq!(
+        messages.len(),
+        2,
+        "should receive a message for each fixed run"
+    );
+    assert_eq!(messages[0].events, ActionEvents::START | ActionEvents::FIRE);
+    assert_eq!(messages[1].events, ActionEvents::FIRE);
+}
+
+#[test]
+fn not_registered() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), bindings![Test::KEY])]),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+
+    app.update();
+
+    assert!(
+        !app.world()
+            .contains_resource::<Messages<ActionEventMessage>>()
+    );
+}
+
+fn receive(mut reader: MessageReader<ActionEventMessage>, mut messages: ResMut<ReceivedMessages>) {
+    messages.extend(reader.read().copied());
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct ReceivedMessages(Vec<ActionEventMessage>);
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Test;
+
+impl Test {
+    const KEY: KeyCode = KeyCode::KeyA;
+}
@@ -0,0 +1,126 @@
+use core::time::Duration;
+
+use bevy::{input::InputPlugin, prelude::*, time::TimeUpdateStrategy};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn on_none() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                MovingAverage::new(10.0),
+                Down::new(0.4),
+                bindings![Test::KEY],
+            )]
+        ),
+    ));
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+
+    app.update();
+
+    let mut actions = app
+        .world_mut()
+        .query_filtered::<(&ActionValue, &TriggerState), With<Action<Test>>>();
+
+    let (&value, &state) = actions.single(app.world()).unwrap();
+    assert_eq!(value, 0.5.into());
+    assert_eq!(state, TriggerState::Fired);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(Test::KEY);
+
+    app.update();
+
+    let (_, &state) = actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::None);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+
+    app.update();
+
+    let (&value, &state) = actions.single(app.world()).unwrap();
+    assert_eq!(value, 1.0.into(), "previous values should be discarded");
+    assert_eq!(state, TriggerState::Fired);
+}
+
+#[test]
+fn derivative() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                Derivative::default(),
+                Down::new(0.1),
+                bindings![GamepadAxis::LeftStickX],
+            )]
+        ),
+    ));
+
+    app.update();
+    app.update();
+
+    let mut actions = app
+        .world_mut()
+        .query_filtered::<(&ActionValue, &TriggerState), With<Action<Test>>>();
+
+    for (axis_value, expected_value, expected_state) in [
+        (0.5, 5.0, TriggerState::Fired),
+        (0.5, 0.0, TriggerState::None),
+        (1.0, 5.0, TriggerState::Fired),
+    ] {
+        app.world_mut()
+            .get_mut::<Gamepad>(gamepad)
+            .unwrap()
+            .analog_mut()
+            .set(GamepadAxis::LeftStickX, axis_value);
+
+        app.update();
+
+        let (&value, &state) = actions.single(app.world()).unwrap();
+        assert!(
+            (value.as_axis1d() - expected_value).abs() < 1e-4,
+            "`{value:?}` should be `{expected_value}`"
+        );
+        assert_eq!(
+            state, expected_state,
+            "motion after a reset shouldn't be lost"
+        );
+    }
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(f32)]
+struct Test;
+
+impl Test {
+    const KEY: KeyCode = KeyCode::KeyA;
+}
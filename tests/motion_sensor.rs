@@ -0,0 +1,218 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn axes() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let sensor = MotionSensor::new(&mut app.world_mut().resource_mut::<CustomInputs>());
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[
+            (Action::<Aim>::new(), bindings![sensor.axis_2d()]),
+            (Action::<Rotation>::new(), bindings![sensor.axis_3d()]),
+        ]),
+    ));
+    let sensor = app.world_mut().spawn(sensor).id();
+
+    push_samples(&mut app, sensor, Vec3::new(1.0, -2.0, 0.5));
+
+    app.update();
+
+    let mut aim = app.world_mut().query::<(&Action<Aim>, &TriggerState)>();
+    let (&value, &state) = aim.single(app.world()).unwrap();
+    assert!(value.abs_diff_eq(Vec2::new(0.2, 0.1), 1e-6));
+    assert_eq!(state, TriggerState::Fired);
+
+    let mut rotation = app.world_mut().query::<&Action<Rotation>>();
+    let value = **rotation.single(app.world()).unwrap();
+    assert!(value.abs_diff_eq(Vec3::new(0.1, -0.2, 0.05), 1e-6));
+
+    app.update();
+
+    let (&value, &state) = aim.single(app.world()).unwrap();
+    assert_eq!(*value, Vec2::ZERO, "should reset without samples");
+    assert_eq!(state, TriggerState::None);
+}
+
+#[test]
+fn despawn() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let sensor = MotionSensor::new(&mut app.world_mut().resource_mut::<CustomInputs>());
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[
+            (Action::<Aim>::new(), bindings![sensor.axis_2d()]),
+            (Action::<Rotation>::new(), bindings![sensor.axis_3d()]),
+        ]),
+    ));
+    let sensor = app.world_mut().spawn(sensor).id();
+
+    push_samples(&mut app, sensor, Vec3::X);
+
+    app.update();
+
+    let mut aim = app.world_mut().query::<&Action<Aim>>();
+    let value = **aim.single(app.world()).unwrap();
+    assert_ne!(value, Vec2::ZERO);
+
+    app.world_mut().despawn(sensor);
+    assert!(app.world().resource::<CustomInputs>().is_empty());
+
+    app.update();
+
+    let value = **aim.single(app.world()).unwrap();
+    assert_eq!(value, Vec2::ZERO, "should stop after despawn");
+
+    let mut rotation = app.world_mut().query::<&Action<Rotation>>();
+    let value = **rotation.single(app.world()).unwrap();
+    assert_eq!(value, Vec3::ZERO);
+}
+
+#[test]
+fn calibration() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let sensor = MotionSensor::new(&mut app.world_mut().resource_mut::<CustomInputs>());
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Aim>::new(), bindings![sensor.axis_2d()])]),
+    ));
+    let sensor = app.world_mut().spawn(sensor).id();
+
+    let bias = Vec3::new(0.02, -0.01, 0.0);
+    app.world_mut()
+        .get_mut::<MotionSensor>(sensor)
+        .unwrap()
+        .start_calibration();
+    push_samples(&mut app, sensor, bias);
+
+    app.update();
+
+    let mut aim = app.world_mut().query::<&Action<Aim>>();
+    let value = **aim.single(app.world()).unwrap();
+    assert_eq!(
+        value,
+        Vec2::ZERO,
+        "output should be disabled during calibration"
+    );
+
+    let mut motion_sensor = app.world_mut().get_mut::<MotionSensor>(sensor).unwrap();
+    motion_sensor.finish_calibration();
+    assert!(motion_sensor.offset.abs_diff_eq(bias, 1e-6));
+
+    push_samples(&mut app, sensor, bias + Vec3::X);
+
+    app.update();
+
+    let value = **aim.single(app.world()).unwrap();
+    assert!(value.abs_diff_eq(Vec2::new(0.0, 0.1), 1e-6));
+}
+
+#[test]
+fn ratchet() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let sensor = MotionSensor::new(&mut app.world_mut().resource_mut::<CustomInputs>())
+        .with_ratchet(KeyCode::Space);
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[
+            (Action::<Aim>::new(), bindings![sensor.axis_2d()]),
+            (Action::<Rotation>::new(), bindings![sensor.axis_3d()]),
+        ]),
+    ));
+    let sensor = app.world_mut().spawn(sensor).id();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::Space);
+    push_samples(&mut app, sensor, Vec3::X);
+
+    app.update();
+
+    assert!(app.world().get::<MotionSensor>(sensor).unwrap().ratchet);
+
+    let mut aim = app.world_mut().query::<&Action<Aim>>();
+    let value = **aim.single(app.world()).unwrap();
+    assert_eq!(value, Vec2::ZERO, "should be disabled while holding");
+
+    let mut rotation = app.world_mut().query::<&Action<Rotation>>();
+    let value = **rotation.single(app.world()).unwrap();
+    assert_eq!(value, Vec3::ZERO);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(KeyCode::Space);
+    push_samples(&mut app, sensor, Vec3::X);
+
+    app.update();
+
+    assert!(!app.world().get::<MotionSensor>(sensor).unwrap().ratchet);
+
+    let value = **aim.single(app.world()).unwrap();
+    assert!(value.abs_diff_eq(Vec2::new(0.0, 0.1), 1e-6));
+
+    let value = **rotation.single(app.world()).unwrap();
+    assert!(value.abs_diff_eq(Vec3::new(0.1, 0.0, 0.0), 1e-6));
+}
+
+#[test]
+fn manual_ratchet() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let sensor = MotionSensor::new(&mut app.world_mut().resource_mut::<CustomInputs>());
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Aim>::new(), bindings![sensor.axis_2d()])]),
+    ));
+    let sensor = app.world_mut().spawn(sensor).id();
+
+    app.world_mut()
+        .get_mut::<MotionSensor>(sensor)
+        .unwrap()
+        .ratchet = true;
+    push_samples(&mut app, sensor, Vec3::X);
+
+    app.update();
+
+    let mut aim = app.world_mut().query::<&Action<Aim>>();
+    let value = **aim.single(app.world()).unwrap();
+    assert_eq!(value, Vec2::ZERO, "should be disabled by the flag");
+}
+
+/// Simulates 10 samples at 100 Hz with a constant angular velocity and a level controller.
+fn push_samples(app: &mut App, sensor: Entity, angular_velocity: Vec3) {
+    let mut sensor = app.world_mut().get_mut::<MotionSensor>(sensor).unwrap();
+    for _ in 0..10 {
+        sensor.push_sample(MotionSample::new(angular_velocity, Vec3::Y, 0.01));
+    }
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Aim;
+
+#[derive(InputAction)]
+#[action_output(Vec3)]
+struct Rotation;
@@ -0,0 +1,97 @@
+use core::time::Duration;
+
+use bevy::{input::InputPlugin, prelude::*, time::TimeUpdateStrategy};
+use bevy_enhanced_input::prelude::{TriggerState::*, *};
+use test_log::test;
+
+#[test]
+fn steps() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                StepRepeat::new(0.3, 0.2).with_acceleration(0.5, 0.1),
+                Bindings::spawn(Navigation::new()),
+            )]
+        ),
+    ));
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::ArrowRight);
+
+    let mut actions = app.world_mut().query::<(&Action<Test>, &TriggerState)>();
+
+    let mut states = Vec::new();
+    for _ in 0..6 {
+        app.update();
+        let (&action, &state) = actions.single(app.world()).unwrap();
+        assert_eq!(*action, IVec2::X);
+        states.push(state);
+    }
+
+    assert_eq!(states, [Fired, Ongoing, Ongoing, Fired, Ongoing, Fired]);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(KeyCode::ArrowRight);
+    app.world_mut()
+        .get_mut::<Gamepad>(gamepad)
+        .unwrap()
+        .analog_mut()
+        .set(GamepadAxis::LeftStickY, -0.8);
+
+    app.update();
+
+    let (&action, &state) = actions.single(app.world()).unwrap();
+    assert_eq!(*action, IVec2::NEG_Y);
+    assert_eq!(state, Fired, "direction change should step immediately");
+
+    app.world_mut()
+        .get_mut::<Gamepad>(gamepad)
+        .unwrap()
+        .analog_mut()
+        .set(GamepadAxis::LeftStickY, 0.0);
+    app.world_mut()
+        .get_mut::<Gamepad>(gamepad)
+        .unwrap()
+        .analog_mut()
+        .set(GamepadButton::DPadLeft, 1.0);
+
+    app.update();
+
+    let (&action, &state) = actions.single(app.world()).unwrap();
+    assert_eq!(*action, IVec2::NEG_X);
+    assert_eq!(state, Fired);
+
+    app.world_mut()
+        .get_mut::<Gamepad>(gamepad)
+        .unwrap()
+        .analog_mut()
+        .set(GamepadButton::DPadLeft, 0.0);
+
+    app.update();
+
+    let (&action, &state) = actions.single(app.world()).unwrap();
+    assert_eq!(*action, IVec2::ZERO);
+    assert_eq!(state, None);
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(IVec2)]
+struct Test;
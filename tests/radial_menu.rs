@@ -0,0 +1,146 @@
+use bevy::{ecs::spawn::SpawnWith, input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn select() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<Selections>()
+        .add_observer(
+            |hover: On<RadialHover>, mut selections: ResMut<Selections>| {
+                selections.hovered.push(hover.index);
+            },
+        )
+        .add_observer(
+            |select: On<RadialSelect>, mut selections: ResMut<Selections>| {
+                selections.selected.push(select.index);
+            },
+        )
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        Actions::<TestContext>::spawn(SpawnWith(|context: &mut ActionSpawner<_>| {
+            let open = context
+                .spawn((Action::<OpenWheel>::new(), bindings![OpenWheel::KEY]))
+                .id();
+            context.spawn((
+                Action::<Wheel>::new(),
+                RadialMenu::new(4),
+                Chord::single(open),
+                Bindings::spawn(Cardinal::wasd_keys()),
+            ));
+        })),
+    ));
+
+    app.update();
+
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.press(OpenWheel::KEY);
+    keys.press(KeyCode::KeyW);
+
+    app.update();
+
+    let mut menus = app.world_mut().query::<&RadialMenu>();
+    let menu = menus.single(app.world()).unwrap();
+    assert!(menu.is_open());
+    assert_eq!(menu.hovered(), Some(1));
+
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.release(KeyCode::KeyW);
+    keys.press(KeyCode::KeyA);
+
+    app.update();
+
+    let menu = menus.single(app.world()).unwrap();
+    assert_eq!(menu.hovered(), Some(2));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(OpenWheel::KEY);
+
+    app.update();
+
+    let menu = menus.single(app.world()).unwrap();
+    assert!(!menu.is_open());
+    assert_eq!(menu.selected(), Some(2));
+
+    let selections = app.world().resource::<Selections>();
+    assert_eq!(selections.hovered, [Some(1), Some(2), None]);
+    assert_eq!(selections.selected, [2]);
+}
+
+#[test]
+fn change_detection() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Wheel>::new(),
+                RadialMenu::new(4),
+                Bindings::spawn(Cardinal::wasd_keys()),
+            )]
+        ),
+    ));
+
+    app.update();
+
+    let mut menus = app.world_mut().query::<Ref<RadialMenu>>();
+    let last_changed = menus.single(app.world()).unwrap().last_changed();
+
+    app.update();
+
+    let menu = menus.single(app.world()).unwrap();
+    assert_eq!(
+        menu.last_changed(),
+        last_changed,
+        "shouldn't change while closed"
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyW);
+
+    app.update();
+
+    let menu = menus.single(app.world()).unwrap();
+    assert_ne!(menu.last_changed(), last_changed);
+    let last_changed = menu.last_changed();
+
+    app.update();
+
+    let menu = menus.single(app.world()).unwrap();
+    assert_eq!(
+        menu.last_changed(),
+        last_changed,
+        "shouldn't change while hovering the same slot"
+    );
+}
+
+#[derive(Resource, Default)]
+struct Selections {
+    hovered: Vec<Option<u32>>,
+    selected: Vec<u32>,
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct OpenWheel;
+
+impl OpenWheel {
+    const KEY: KeyCode = KeyCode::Tab;
+}
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Wheel;
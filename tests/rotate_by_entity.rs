@@ -0,0 +1,180 @@
+use core::f32::consts::FRAC_PI_2;
+
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn camera() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_component::<GlobalTransform>()
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let camera = app.world_mut().spawn(GlobalTransform::default()).id();
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Movement>::new(),
+                RotateByEntity::new(camera),
+                bindings![(KeyCode::KeyW, SwizzleAxis::YXZ)],
+            )]
+        ),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyW);
+
+    app.update();
+
+    let mut movement = app.world_mut().query::<&Action<Movement>>();
+    let value = **movement.single(app.world()).unwrap();
+    assert!(value.abs_diff_eq(Vec2::Y, 1e-6));
+
+    *app.world_mut().get_mut::<GlobalTransform>(camera).unwrap() =
+        Transform::from_rotation(Quat::from_rotation_y(FRAC_PI_2)).into();
+
+    app.update();
+
+    let value = **movement.single(app.world()).unwrap();
+    assert!(
+        value.abs_diff_eq(Vec2::NEG_X, 1e-6),
+        "should use the rotation from the same frame"
+    );
+
+    app.world_mut().despawn(camera);
+
+    app.update();
+
+    let value = **movement.single(app.world()).unwrap();
+    assert!(
+        value.abs_diff_eq(Vec2::Y, 1e-6),
+        "should reset the rotation without the entity"
+    );
+}
+
+#[test]
+fn pitched_camera() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_component::<GlobalTransform>()
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let transform =
+        Transform::from_xyz(0.0, 5.0, 5.0).looking_at(Vec3::new(5.0, 0.0, 5.0), Vec3::Y);
+    let camera = app.world_mut().spawn(GlobalTransform::from(transform)).id();
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[
+                (
+                    Action::<Movement>::new(),
+                    RotateByEntity::new(camera),
+                    bindings![(KeyCode::KeyW, SwizzleAxis::YXZ)],
+                ),
+                (
+                    Action::<Flight>::new(),
+                    RotateByEntity::new(camera),
+                    bindings![(KeyCode::KeyW, SwizzleAxis::ZYX, Negate::all())],
+                ),
+                (
+                    Action::<GroundFlight>::new(),
+                    RotateByEntity::new(camera).with_project_to_ground(true),
+                    bindings![(KeyCode::KeyW, SwizzleAxis::ZYX, Negate::all())],
+                ),
+            ]
+        ),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyW);
+
+    app.update();
+
+    let mut movement = app.world_mut().query::<&Action<Movement>>();
+    let value = **movement.single(app.world()).unwrap();
+    assert!(
+        value.abs_diff_eq(Vec2::X, 1e-5),
+        "should move forward along the camera yaw ignoring the pitch"
+    );
+
+    let mut flight = app.world_mut().query::<&Action<Flight>>();
+    let value = **flight.single(app.world()).unwrap();
+    assert!(value.abs_diff_eq(Vec3::new(1.0, -1.0, 0.0).normalize(), 1e-5));
+
+    let mut ground_flight = app.world_mut().query::<&Action<GroundFlight>>();
+    let value = **ground_flight.single(app.world()).unwrap();
+    assert!(value.abs_diff_eq(Vec3::X, 1e-5));
+}
+
+#[test]
+fn vertical_camera() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_component::<GlobalTransform>()
+        .add_input_context::<TestContext>()
+        .finish();
+
+    // Turned left, then pitched straight down.
+    let rotation = Quat::from_rotation_y(FRAC_PI_2) * Quat::from_rotation_x(-FRAC_PI_2);
+    let camera = app
+        .world_mut()
+        .spawn(GlobalTransform::from(Transform::from_rotation(rotation)))
+        .id();
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Movement>::new(),
+                RotateByEntity::new(camera),
+                bindings![(KeyCode::KeyW, SwizzleAxis::YXZ)],
+            )]
+        ),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyW);
+
+    app.update();
+
+    let mut movement = app.world_mut().query::<&Action<Movement>>();
+    let value = **movement.single(app.world()).unwrap();
+    assert!(
+        value.abs_diff_eq(Vec2::NEG_X, 1e-5),
+        "should move towards the top of the view when looking down"
+    );
+
+    // Turned left, then pitched straight up.
+    let rotation = Quat::from_rotation_y(FRAC_PI_2) * Quat::from_rotation_x(FRAC_PI_2);
+    *app.world_mut().get_mut::<GlobalTransform>(camera).unwrap() =
+        Transform::from_rotation(rotation).into();
+
+    app.update();
+
+    let value = **movement.single(app.world()).unwrap();
+    assert!(
+        value.abs_diff_eq(Vec2::NEG_X, 1e-5),
+        "should move away from the top of the view when looking up"
+    );
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Movement;
+
+#[derive(InputAction)]
+#[action_output(Vec3)]
+struct Flight;
+
+#[derive(InputAction)]
+#[action_output(Vec3)]
+struct GroundFlight;
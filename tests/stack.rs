@@ -0,0 +1,139 @@
+use bevy::{ecs::spawn::SpawnWith, input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn conditions() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        Actions::<TestContext>::spawn(SpawnWith(|context: &mut ActionSpawner<_>| {
+            let member1 = context
+                .spawn((Action::<Member1>::new(), bindings![Member1::KEY]))
+                .id();
+            let member2 = context
+                .spawn((Action::<Member2>::new(), bindings![Member2::KEY]))
+                .id();
+            context.spawn((
+                Action::<Test>::new(),
+                ConditionStack::default()
+                    .with(Chord::single(member1))
+                    .with(Chord::single(member2)),
+            ));
+        })),
+    ));
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Member1::KEY);
+
+    app.update();
+
+    let mut actions = app
+        .world_mut()
+        .query_filtered::<&TriggerState, With<Action<Test>>>();
+
+    let state = *actions.single(app.world()).unwrap();
+    assert_eq!(
+        state,
+        TriggerState::Ongoing,
+        "both implicit conditions should be required"
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Member2::KEY);
+
+    app.update();
+
+    let state = *actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::Fired);
+
+    // Stack components can be removed like regular conditions.
+    let mut stacks = app
+        .world_mut()
+        .query_filtered::<Entity, With<ConditionStack>>();
+    let test = stacks.single(app.world()).unwrap();
+    app.world_mut().entity_mut(test).remove::<ConditionStack>();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(Member1::KEY);
+
+    app.update();
+
+    let state = *actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::None);
+}
+
+#[test]
+fn modifiers() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Axis>::new(),
+                ModifierStack::default()
+                    .with(Scale::splat(2.0))
+                    .with(Negate::all())
+                    .with(Scale::splat(3.0)),
+                bindings![Axis::KEY],
+            )]
+        ),
+    ));
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Axis::KEY);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&Action<Axis>>();
+
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(*action, -6.0);
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Test;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Member1;
+
+impl Member1 {
+    const KEY: KeyCode = KeyCode::KeyA;
+}
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Member2;
+
+impl Member2 {
+    const KEY: KeyCode = KeyCode::KeyB;
+}
+
+#[derive(InputAction)]
+#[action_output(f32)]
+struct Axis;
+
+impl Axis {
+    const KEY: KeyCode = KeyCode::KeyC;
+}
@@ -0,0 +1,214 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn analog() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(
+                TestContext[(
+                    Action::<Test>::new(),
+                    ActionSettings {
+                        value_change_epsilon: Some(0.1),
+                        ..Default::default()
+                    },
+                )]
+            ),
+        ))
+        .id();
+
+    app.world_mut()
+        .entity_mut(context)
+        .mock_once::<TestContext, Test>(TriggerState::Fired, 0.5)
+        .unwrap();
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&ActionEvents>();
+    let events = *actions.single(app.world()).unwrap();
+    assert_eq!(
+        events,
+        ActionEvents::START | ActionEvents::FIRE | ActionEvents::VALUE_CHANGED
+    );
+
+    app.world_mut()
+        .entity_mut(context)
+        .mock_once::<TestContext, Test>(TriggerState::Fired, 0.55)
+        .unwrap();
+
+    app.update();
+
+    let events = *actions.single(app.world()).unwrap();
+    assert_eq!(
+        events,
+        ActionEvents::FIRE,
+        "change within epsilon shouldn't be reported"
+    );
+
+    app.world_mut()
+        .entity_mut(context)
+        .mock_once::<TestContext, Test>(TriggerState::Fired, 0.8)
+        .unwrap();
+
+    app.update();
+
+    let events = *actions.single(app.world()).unwrap();
+    assert_eq!(events, ActionEvents::FIRE | ActionEvents::VALUE_CHANGED);
+}
+
+#[test]
+fn slow_change() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(
+                TestContext[(
+                    Action::<Test>::new(),
+                    ActionSettings {
+                        value_change_epsilon: Some(0.1),
+                        ..Default::default()
+                    },
+                )]
+            ),
+        ))
+        .id();
+
+    let mut actions = app.world_mut().query::<&ActionEvents>();
+    for value in [0.04, 0.08] {
+        app.world_mut()
+            .entity_mut(context)
+            .mock_once::<TestContext, Test>(TriggerState::Fired, value)
+            .unwrap();
+
+        app.update();
+
+        let events = *actions.single(app.world()).unwrap();
+        assert!(
+            !events.contains(ActionEvents::VALUE_CHANGED),
+            "`{value}` is within epsilon from the initial value"
+        );
+    }
+
+    app.world_mut()
+        .entity_mut(context)
+        .mock_once::<TestContext, Test>(TriggerState::Fired, 0.12)
+        .unwrap();
+
+    app.update();
+
+    let events = *actions.single(app.world()).unwrap();
+    assert!(
+        events.contains(ActionEvents::VALUE_CHANGED),
+        "changes smaller than epsilon should accumulate"
+    );
+
+    app.world_mut()
+        .entity_mut(context)
+        .mock_once::<TestContext, Test>(TriggerState::Fired, 0.16)
+        .unwrap();
+
+    app.update();
+
+    let events = *actions.single(app.world()).unwrap();
+    assert!(
+        !events.contains(ActionEvents::VALUE_CHANGED),
+        "should compare with the last reported value"
+    );
+}
+
+#[test]
+fn disabled_by_default() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((TestContext, actions!(TestContext[Action::<Test>::new()])))
+        .id();
+
+    app.world_mut()
+        .entity_mut(context)
+        .mock_once::<TestContext, Test>(TriggerState::Fired, 0.5)
+        .unwrap();
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&ActionEvents>();
+    let events = *actions.single(app.world()).unwrap();
+    assert!(!events.contains(ActionEvents::VALUE_CHANGED));
+}
+
+#[test]
+fn observer() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<Values>()
+        .add_input_context::<TestContext>()
+        .add_observer(
+            |changed: On<ValueChanged<Test>>, mut values: ResMut<Values>| {
+                values.push(changed.value);
+            },
+        )
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                ActionSettings {
+                    value_change_epsilon: Some(0.0),
+                    ..Default::default()
+                },
+                bindings![Test::KEY],
+            )]
+        ),
+    ));
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+
+    app.update();
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(Test::KEY);
+
+    app.update();
+
+    assert_eq!(**app.world().resource::<Values>(), [1.0, 0.0]);
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct Values(Vec<f32>);
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(f32)]
+struct Test;
+
+impl Test {
+    const KEY: KeyCode = KeyCode::KeyA;
+}
@@ -0,0 +1,202 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn resource() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .add_input_condition::<UiBlocker>()
+        .add_input_resource::<UiHovered>()
+        .init_resource::<UiHovered>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                UiBlocker::default(),
+                bindings![Test::KEY]
+            )]
+        ),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+
+    app.update();
+
+    let mut actions = app
+        .world_mut()
+        .query_filtered::<&TriggerState, With<Action<Test>>>();
+
+    let state = *actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::Fired);
+
+    app.world_mut().resource_mut::<UiHovered>().0 = true;
+
+    app.update();
+
+    let state = *actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::None, "should be blocked by UI");
+}
+
+#[test]
+fn component() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .add_input_modifier::<SensitivityScale>()
+        .add_input_component::<Sensitivity>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            Sensitivity(2.0),
+            actions!(TestContext[(Action::<Test>::new(), bindings![Test::KEY])]),
+        ))
+        .id();
+
+    let action = app
+        .world_mut()
+        .query_filtered::<Entity, With<Action<Test>>>()
+        .single(app.world())
+        .unwrap();
+    app.world_mut()
+        .entity_mut(action)
+        .insert(SensitivityScale::new(context));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+
+    app.update();
+
+    let value = *app.world().get::<ActionValue>(action).unwrap();
+    assert_eq!(value, 2.0.into());
+
+    app.world_mut().get_mut::<Sensitivity>(context).unwrap().0 = 0.5;
+
+    app.update();
+
+    let value = *app.world().get::<ActionValue>(action).unwrap();
+    assert_eq!(value, 0.5.into());
+}
+
+#[test]
+#[should_panic = "can't be registered"]
+fn condition_as_component() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .add_input_component::<Down>()
+        .finish();
+}
+
+#[test]
+#[should_panic = "can't be registered"]
+fn action_data_as_component() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_component::<ActionValue>();
+}
+
+#[test]
+fn action_as_component() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_component::<Action<Test>>()
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut()
+        .spawn((TestContext, actions!(TestContext[Action::<Test>::new()])));
+
+    app.update();
+}
+
+/// Blocks the action while the cursor is over UI.
+#[derive(Component, Debug, Default)]
+struct UiBlocker {
+    hovered: bool,
+}
+
+impl InputCondition for UiBlocker {
+    fn evaluate(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        _value: ActionValue,
+    ) -> TriggerState {
+        if self.hovered {
+            TriggerState::None
+        } else {
+            TriggerState::Fired
+        }
+    }
+
+    fn kind(&self) -> ConditionKind {
+        ConditionKind::Blocker
+    }
+
+    fn read_world(&mut self, world: &InputWorld) {
+        self.hovered = world
+            .resource::<UiHovered>()
+            .is_some_and(|hovered| hovered.0);
+    }
+}
+
+#[derive(Resource, Default)]
+struct UiHovered(bool);
+
+/// Scales the value by the [`Sensitivity`] of an entity.
+#[derive(Component, Debug)]
+struct SensitivityScale {
+    entity: Entity,
+    sensitivity: f32,
+}
+
+impl SensitivityScale {
+    fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            sensitivity: 1.0,
+        }
+    }
+}
+
+impl InputModifier for SensitivityScale {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        (value.as_axis1d() * self.sensitivity).into()
+    }
+
+    fn read_world(&mut self, world: &InputWorld) {
+        self.sensitivity = world
+            .get::<Sensitivity>(self.entity)
+            .map_or(1.0, |sensitivity| sensitivity.0);
+    }
+}
+
+#[derive(Component)]
+struct Sensitivity(f32);
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(f32)]
+struct Test;
+
+impl Test {
+    const KEY: KeyCode = KeyCode::KeyA;
+}